@@ -0,0 +1,152 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Merkle allowlists of the addresses eligible for a campaign and the amount each one receives.
+//!
+//! The trees are compatible with OpenZeppelin's [`StandardMerkleTree`] with the
+//! `["address", "uint256"]` leaf encoding, where the amount is the number of attotokens. Each leaf
+//! is the double Keccak-256 hash of the ABI encoded address and amount, and each inner node is the
+//! Keccak-256 hash of its two children sorted in ascending order.
+//!
+//! [`StandardMerkleTree`]: https://github.com/OpenZeppelin/merkle-tree
+
+use alloy_primitives::{keccak256, Address, B256, U256};
+use linera_sdk::base::Amount;
+use serde::{Deserialize, Serialize};
+
+use crate::ethereum::EthereumHash;
+
+/// A proof that an address is in a campaign's allowlist with an `amount`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, async_graphql::InputObject)]
+pub struct AllowlistProof {
+    /// The amount allowlisted for the address.
+    pub amount: Amount,
+    /// The sibling hashes from the address's leaf up to the root.
+    pub proof: Vec<EthereumHash>,
+}
+
+impl AllowlistProof {
+    /// Checks if this proves that the `address` is in the allowlist with the Merkle `root`.
+    pub fn verify(&self, root: B256, address: Address) -> bool {
+        let leaf = leaf_hash(address, self.amount);
+
+        self.proof
+            .iter()
+            .fold(leaf, |node, sibling| hash_pair(node, sibling.0))
+            == root
+    }
+}
+
+/// Returns the hash of the leaf for an `address` and its allowlisted `amount`.
+pub fn leaf_hash(address: Address, amount: Amount) -> B256 {
+    let mut encoded = [0_u8; 64];
+    encoded[12..32].copy_from_slice(address.as_slice());
+    encoded[32..].copy_from_slice(&U256::from(u128::from(amount)).to_be_bytes::<32>());
+
+    keccak256(keccak256(encoded))
+}
+
+/// Returns the hash of an inner node with the two child nodes.
+fn hash_pair(first: B256, second: B256) -> B256 {
+    let (low, high) = if first <= second {
+        (first, second)
+    } else {
+        (second, first)
+    };
+
+    let mut concatenated = [0_u8; 64];
+    concatenated[..32].copy_from_slice(low.as_slice());
+    concatenated[32..].copy_from_slice(high.as_slice());
+
+    keccak256(concatenated)
+}
+
+/// A Merkle allowlist, laid out like OpenZeppelin's `StandardMerkleTree`.
+#[derive(Clone, Debug)]
+pub struct AllowlistTree {
+    /// The tree's nodes, starting with the root, where the children of node `i` are the nodes
+    /// `2 * i + 1` and `2 * i + 2`.
+    nodes: Vec<B256>,
+    /// The allowlisted entries, in their original order, with the index of their leaf node.
+    entries: Vec<(Address, Amount, usize)>,
+}
+
+impl AllowlistTree {
+    /// Builds the [`AllowlistTree`] with the allowlisted addresses and their amounts.
+    ///
+    /// # Panics
+    ///
+    /// If there are no `entries`.
+    pub fn new(entries: &[(Address, Amount)]) -> Self {
+        assert!(!entries.is_empty(), "Allowlist must not be empty");
+
+        let mut leaves = entries
+            .iter()
+            .enumerate()
+            .map(|(index, (address, amount))| (leaf_hash(*address, *amount), index))
+            .collect::<Vec<_>>();
+        leaves.sort();
+
+        let node_count = 2 * leaves.len() - 1;
+        let mut nodes = vec![B256::ZERO; node_count];
+        let mut leaf_indices = vec![0; entries.len()];
+
+        for (position, (leaf, entry_index)) in leaves.into_iter().enumerate() {
+            let node_index = node_count - 1 - position;
+            nodes[node_index] = leaf;
+            leaf_indices[entry_index] = node_index;
+        }
+
+        for index in (0..node_count - entries.len()).rev() {
+            nodes[index] = hash_pair(nodes[2 * index + 1], nodes[2 * index + 2]);
+        }
+
+        AllowlistTree {
+            nodes,
+            entries: entries
+                .iter()
+                .zip(leaf_indices)
+                .map(|(&(address, amount), node_index)| (address, amount, node_index))
+                .collect(),
+        }
+    }
+
+    /// Returns the Merkle root, to be set in the campaign's eligibility rule.
+    pub fn root(&self) -> B256 {
+        self.nodes[0]
+    }
+
+    /// Returns the tree's nodes, starting with the root.
+    pub fn nodes(&self) -> &[B256] {
+        &self.nodes
+    }
+
+    /// Returns the allowlisted entries, in their original order, with the index of their leaf
+    /// node.
+    pub fn entries(&self) -> &[(Address, Amount, usize)] {
+        &self.entries
+    }
+
+    /// Returns the [`AllowlistProof`] for the leaf at `node_index`.
+    pub fn proof(&self, node_index: usize) -> AllowlistProof {
+        let (_, amount, _) = self
+            .entries
+            .iter()
+            .find(|(_, _, leaf_index)| *leaf_index == node_index)
+            .expect("Node index should be of a leaf");
+
+        let mut proof = Vec::new();
+        let mut index = node_index;
+
+        while index > 0 {
+            let sibling = if index % 2 == 1 { index + 1 } else { index - 1 };
+            proof.push(self.nodes[sibling].into());
+            index = (index - 1) / 2;
+        }
+
+        AllowlistProof {
+            amount: *amount,
+            proof,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A command-line tool that builds the Merkle allowlist of a campaign from a CSV snapshot.
+
+#[cfg(test)]
+mod allowlist_builder_unit_tests;
+
+use std::{collections::HashSet, fs, path::PathBuf, str::FromStr};
+
+use airdrop_demo::allowlist::AllowlistTree;
+use alloy_primitives::Address;
+use anyhow::{bail, ensure, Context};
+use clap::Parser;
+use linera_sdk::{base::Amount, serde_json};
+
+/// Builds a Merkle allowlist from a CSV file with `address,amount` rows.
+///
+/// Prints the Merkle root to use in the campaign's `Allowlisted` eligibility rule, and writes
+/// the tree in OpenZeppelin's `StandardMerkleTree` format to `tree.json` and the proof of each
+/// address to `proofs.json` in the output directory.
+#[derive(Parser)]
+#[command(version)]
+struct Arguments {
+    /// The CSV file with the allowlisted addresses and their amounts in tokens, optionally
+    /// starting with an `address,amount` header.
+    input: PathBuf,
+    /// The directory to write the tree and the proofs to.
+    #[arg(long, default_value = ".")]
+    output_dir: PathBuf,
+}
+
+fn main() -> anyhow::Result<()> {
+    let arguments = Arguments::parse();
+
+    let csv = fs::read_to_string(&arguments.input)
+        .with_context(|| format!("Failed to read {}", arguments.input.display()))?;
+    let entries = parse_entries(&csv)?;
+    let tree = AllowlistTree::new(&entries);
+
+    let values = tree
+        .entries()
+        .iter()
+        .map(|(address, amount, leaf_index)| {
+            serde_json::json!({
+                "value": [address.to_checksum(None), u128::from(*amount).to_string()],
+                "treeIndex": leaf_index,
+            })
+        })
+        .collect::<Vec<_>>();
+    let tree_json = serde_json::json!({
+        "format": "standard-v1",
+        "leafEncoding": ["address", "uint256"],
+        "tree": tree.nodes(),
+        "values": values,
+    });
+
+    let proofs = tree
+        .entries()
+        .iter()
+        .map(|(address, _, leaf_index)| {
+            let proof = serde_json::to_value(tree.proof(*leaf_index))
+                .expect("`AllowlistProof` should be serializable");
+            (address.to_checksum(None), proof)
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    fs::create_dir_all(&arguments.output_dir)?;
+    fs::write(
+        arguments.output_dir.join("tree.json"),
+        serde_json::to_string_pretty(&tree_json)?,
+    )?;
+    fs::write(
+        arguments.output_dir.join("proofs.json"),
+        serde_json::to_string_pretty(&proofs)?,
+    )?;
+
+    println!("{}", tree.root());
+
+    Ok(())
+}
+
+/// Parses and validates the `address,amount` rows of the allowlist.
+///
+/// Addresses with mixed-case letters must have a valid [EIP-55] checksum, and each address may
+/// only appear once.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+fn parse_entries(csv: &str) -> anyhow::Result<Vec<(Address, Amount)>> {
+    let mut entries = Vec::new();
+    let mut seen_addresses = HashSet::new();
+
+    for (index, line) in csv.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || (index == 0 && line.eq_ignore_ascii_case("address,amount")) {
+            continue;
+        }
+
+        let Some((address_string, amount_string)) = line.split_once(',') else {
+            bail!("Line {line_number} is not an `address,amount` row");
+        };
+        let address_string = address_string.trim();
+
+        let address = Address::from_str(address_string)
+            .with_context(|| format!("Invalid address on line {line_number}"))?;
+        let hex_digits = address_string.trim_start_matches("0x");
+        let is_mixed_case = hex_digits.chars().any(|digit| digit.is_ascii_uppercase())
+            && hex_digits.chars().any(|digit| digit.is_ascii_lowercase());
+        ensure!(
+            !is_mixed_case || address.to_checksum(None)[2..] == *hex_digits,
+            "Invalid address checksum on line {line_number}"
+        );
+        ensure!(
+            seen_addresses.insert(address),
+            "Duplicate address {address} on line {line_number}"
+        );
+
+        let amount = Amount::from_str(amount_string.trim())
+            .with_context(|| format!("Invalid amount on line {line_number}"))?;
+
+        entries.push((address, amount));
+    }
+
+    ensure!(!entries.is_empty(), "Allowlist has no addresses");
+
+    Ok(entries)
+}
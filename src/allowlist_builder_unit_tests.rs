@@ -0,0 +1,120 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_primitives::{address, Address};
+use linera_sdk::base::Amount;
+
+use super::parse_entries;
+
+/// An address whose EIP-55 checksum has both uppercase and lowercase letters.
+const ADDRESS: Address = address!("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+
+/// Tests if rows are parsed with an optional header, surrounding whitespace and empty lines.
+#[test]
+fn parses_entries() {
+    let csv = "address,amount\n\
+        0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed, 10\n\
+        \n\
+        0x1111111111111111111111111111111111111111,2.5\n";
+
+    let entries = parse_entries(csv).expect("Failed to parse allowlist");
+
+    assert_eq!(
+        entries,
+        [
+            (ADDRESS, Amount::from_tokens(10)),
+            (
+                Address::repeat_byte(0x11),
+                Amount::from_attos(2_500_000_000_000_000_000)
+            ),
+        ]
+    );
+}
+
+/// Tests if addresses without letters of mixed case are accepted without a checksum.
+#[test]
+fn accepts_single_case_addresses() {
+    let csv = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed,1\n\
+        0xFB6916095CA1DF60BB79CE92CE3EA74C37C5D359,2\n";
+
+    let entries = parse_entries(csv).expect("Failed to parse allowlist");
+
+    assert_eq!(
+        entries,
+        [
+            (ADDRESS, Amount::from_tokens(1)),
+            (
+                address!("fB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"),
+                Amount::from_tokens(2)
+            ),
+        ]
+    );
+}
+
+/// Tests if an address that appears twice is rejected, even with a different case.
+#[test]
+fn rejects_duplicate_addresses() {
+    let csv = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,1\n\
+        0x1111111111111111111111111111111111111111,1\n\
+        0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed,2\n";
+
+    let error = parse_entries(csv).expect_err("Duplicate address should be rejected");
+
+    assert_eq!(
+        error.to_string(),
+        format!("Duplicate address {ADDRESS} on line 3")
+    );
+}
+
+/// Tests if a mixed-case address with an invalid EIP-55 checksum is rejected.
+#[test]
+fn rejects_bad_checksum() {
+    let csv = "address,amount\n\
+        0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,1\n";
+
+    let error = parse_entries(csv).expect_err("Bad checksum should be rejected");
+
+    assert_eq!(error.to_string(), "Invalid address checksum on line 2");
+}
+
+/// Tests if malformed rows are rejected with the number of their line.
+#[test]
+fn rejects_malformed_rows() {
+    let cases = [
+        (
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed\n",
+            "Line 1 is not an `address,amount` row",
+        ),
+        (
+            "address,amount\nnot-an-address,1\n",
+            "Invalid address on line 2",
+        ),
+        (
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,lots\n",
+            "Invalid amount on line 1",
+        ),
+        (
+            "0x1111111111111111111111111111111111111111,1\naddress,amount\n",
+            "Invalid address on line 2",
+        ),
+    ];
+
+    for (csv, expected_error) in cases {
+        let error = parse_entries(csv).expect_err("Malformed row should be rejected");
+
+        assert_eq!(
+            error.to_string(),
+            expected_error,
+            "Unexpected error for {csv:?}"
+        );
+    }
+}
+
+/// Tests if an allowlist without addresses is rejected.
+#[test]
+fn rejects_empty_allowlist() {
+    let error =
+        parse_entries("address,amount\n\n").expect_err("Empty allowlist should be rejected");
+
+    assert_eq!(error.to_string(), "Allowlist has no addresses");
+}
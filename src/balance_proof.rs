@@ -0,0 +1,164 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verification of Space-and-Time Proof-of-SQL results for the eligibility balance query.
+
+use alloy_primitives::{Address, U256};
+use linera_sdk::bcs;
+use proof_of_sql::{
+    base::{
+        commitment::{CommitmentEvaluationProof, InnerProductProof, QueryCommitments},
+        database::{OwnedColumn, OwnedTable},
+        scalar::Scalar,
+    },
+    sql::{parse::QueryExpr, proof::VerifiableQueryResult},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::network::Network;
+
+/// The commitments to the Space-and-Time tables that balance proofs are verified against.
+pub type TableCommitments =
+    QueryCommitments<<InnerProductProof as CommitmentEvaluationProof>::Commitment>;
+
+/// A Proof-of-SQL result for the balance query of a claimer's address at a snapshot block.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, async_graphql::InputObject)]
+pub struct BalanceProof {
+    /// The block used as the balance snapshot in the proven query.
+    pub snapshot_block: u64,
+    /// The BCS serialized [`VerifiableQueryResult`] returned by the prover.
+    pub verifiable_result: Vec<u8>,
+}
+
+/// A balance proven by a [`BalanceProof`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ProvenBalance {
+    pub snapshot_block: u64,
+    pub balance: U256,
+}
+
+impl BalanceProof {
+    /// Verifies the proof of the balance of an `address` on a `network` against the BCS
+    /// serialized [`TableCommitments`], returning the proven balance.
+    ///
+    /// Addresses without any balance records up to the snapshot block have a zero balance.
+    pub fn verify(
+        &self,
+        network: &Network,
+        address: Address,
+        serialized_commitments: &[u8],
+    ) -> Result<ProvenBalance, BalanceProofError> {
+        let commitments = deserialize_commitments(serialized_commitments)?;
+        let verifiable_result =
+            bcs::from_bytes::<VerifiableQueryResult<InnerProductProof>>(&self.verifiable_result)
+                .map_err(|_| BalanceProofError::MalformedProof)?;
+
+        let query_text = balance_query(network, address, self.snapshot_block);
+        let query = QueryExpr::try_new(
+            query_text
+                .parse()
+                .map_err(|_| BalanceProofError::InvalidQuery)?,
+            network
+                .schema()
+                .parse()
+                .map_err(|_| BalanceProofError::InvalidQuery)?,
+            &commitments,
+        )
+        .map_err(|_| BalanceProofError::InvalidQuery)?;
+
+        let result = verifiable_result
+            .verify(query.proof_expr(), &commitments, &())
+            .map_err(|_| BalanceProofError::VerificationFailed)?;
+
+        Ok(ProvenBalance {
+            snapshot_block: self.snapshot_block,
+            balance: latest_balance(&result.table)?,
+        })
+    }
+}
+
+/// Deserializes the BCS serialized [`TableCommitments`] that balance proofs are verified against.
+pub fn deserialize_commitments(
+    serialized_commitments: &[u8],
+) -> Result<TableCommitments, BalanceProofError> {
+    bcs::from_bytes(serialized_commitments).map_err(|_| BalanceProofError::InvalidCommitments)
+}
+
+/// Returns the provable SQL query for the balance records of an `address` on a `network` up to
+/// the `snapshot_block`.
+///
+/// The records are filtered in the proven query, and the latest one is selected when reading the
+/// verified result.
+pub fn balance_query(network: &Network, address: Address, snapshot_block: u64) -> String {
+    let lowercase_address = address.to_string().to_lowercase();
+    let schema = network.schema();
+
+    format!(
+        "SELECT BALANCE, BLOCK_NUMBER FROM {schema}.NATIVE_WALLETS \
+        WHERE WALLET_ADDRESS = '{lowercase_address}' AND BLOCK_NUMBER <= {snapshot_block}"
+    )
+}
+
+/// Reads the balance of the record with the highest block number in a verified query result.
+fn latest_balance<S: Scalar>(table: &OwnedTable<S>) -> Result<U256, BalanceProofError> {
+    let column = |name: &str| {
+        table
+            .inner_table()
+            .iter()
+            .find(|(identifier, _)| identifier.as_str().eq_ignore_ascii_case(name))
+            .map(|(_, column)| column)
+            .ok_or(BalanceProofError::UnexpectedResult)
+    };
+
+    let OwnedColumn::BigInt(block_numbers) = column("BLOCK_NUMBER")? else {
+        return Err(BalanceProofError::UnexpectedResult);
+    };
+
+    let Some(latest_row) = block_numbers
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, block_number)| **block_number)
+        .map(|(row, _)| row)
+    else {
+        return Ok(U256::ZERO);
+    };
+
+    let balance = match column("BALANCE")? {
+        OwnedColumn::BigInt(balances) => u64::try_from(balances[latest_row])
+            .map(U256::from)
+            .map_err(|_| BalanceProofError::UnexpectedResult)?,
+        OwnedColumn::Int128(balances) => u128::try_from(balances[latest_row])
+            .map(U256::from)
+            .map_err(|_| BalanceProofError::UnexpectedResult)?,
+        OwnedColumn::VarChar(balances) => balances[latest_row]
+            .parse()
+            .map_err(|_| BalanceProofError::UnexpectedResult)?,
+        _ => return Err(BalanceProofError::UnexpectedResult),
+    };
+
+    Ok(balance)
+}
+
+/// Errors that may occur when verifying a [`BalanceProof`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BalanceProofError {
+    InvalidCommitments,
+    MalformedProof,
+    InvalidQuery,
+    VerificationFailed,
+    UnexpectedResult,
+}
+
+impl std::fmt::Display for BalanceProofError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let reason = match self {
+            BalanceProofError::InvalidCommitments => "Invalid Proof-of-SQL table commitments",
+            BalanceProofError::MalformedProof => "Balance proof could not be deserialized",
+            BalanceProofError::InvalidQuery => "Balance query could not be planned",
+            BalanceProofError::VerificationFailed => "Balance proof verification failed",
+            BalanceProofError::UnexpectedResult => "Unexpected balance query result",
+        };
+
+        formatter.write_str(reason)
+    }
+}
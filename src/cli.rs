@@ -0,0 +1,295 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A command-line tool to sign, submit and verify airdrop claims without a browser wallet.
+
+#[cfg(test)]
+mod cli_unit_tests;
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use airdrop_demo::{
+    allowlist::AllowlistProof,
+    balance_proof::BalanceProof,
+    signing::{recover_claim_signer, sign_claim},
+    AirDropClaim, ApplicationAbi, CampaignId, Operation,
+};
+use alloy_primitives::{Address, Signature};
+use anyhow::{ensure, Context};
+use async_graphql::InputType;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use k256::ecdsa::SigningKey;
+use linera_sdk::{
+    abis::fungible::Account,
+    base::{AccountOwner, Amount, ApplicationId, ChainId},
+    bcs, serde_json,
+};
+use serde::de::DeserializeOwned;
+
+/// Signs, submits and verifies airdrop claims for Ethereum addresses.
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Signs a claim with an Ethereum private key.
+    Sign {
+        #[command(flatten)]
+        claim: ClaimArguments,
+        #[command(flatten)]
+        key: KeyArguments,
+        #[command(flatten)]
+        proofs: ProofArguments,
+        /// The Space-and-Time API token used by the creator chain to check the claimer's
+        /// eligibility.
+        #[arg(long, default_value = "")]
+        api_token: String,
+        /// What to print for the signed claim.
+        #[arg(long, value_enum, default_value_t = Output::Signature)]
+        output: Output,
+    },
+    /// Verifies the signature of a claim, printing the Ethereum address that signed it.
+    Verify {
+        #[command(flatten)]
+        claim: ClaimArguments,
+        /// The claim's signature, as a hexadecimal string.
+        #[arg(long)]
+        signature: Signature,
+        /// Fails if the claim wasn't signed by this Ethereum address.
+        #[arg(long)]
+        expected_signer: Option<Address>,
+    },
+}
+
+/// The fields of a claim that are covered by its signature.
+#[derive(Args)]
+struct ClaimArguments {
+    /// The ID of the airdrop application.
+    #[arg(long)]
+    application_id: ApplicationId,
+    /// The campaign to claim the airdrop from.
+    #[arg(long)]
+    campaign: u32,
+    /// The chain of the account that receives the airdropped tokens.
+    #[arg(long)]
+    chain_id: ChainId,
+    /// The owner of the account that receives the airdropped tokens.
+    #[arg(long)]
+    owner: AccountOwner,
+    /// The fee paid to the relayer that submits the claim.
+    #[arg(long)]
+    relayer_fee: Option<Amount>,
+}
+
+impl ClaimArguments {
+    fn application_id(&self) -> ApplicationId<ApplicationAbi> {
+        self.application_id.with_abi()
+    }
+
+    fn destination(&self) -> Account {
+        Account {
+            chain_id: self.chain_id,
+            owner: self.owner,
+        }
+    }
+}
+
+/// The proofs attached to a claim, which aren't covered by its signature.
+#[derive(Args)]
+struct ProofArguments {
+    /// The `proofs.json` file written by `airdrop_demo_allowlist`, from which the allowlist proof
+    /// of the signer's address is attached to the claim.
+    #[arg(long)]
+    allowlist_proofs: Option<PathBuf>,
+    /// A JSON file with the Proof-of-SQL `BalanceProof` of the signer's balance, with its
+    /// `snapshot_block` and the BCS bytes of its `verifiable_result`, which is attached to the
+    /// claim instead of querying the Space-and-Time Gateway.
+    #[arg(long)]
+    balance_proof: Option<PathBuf>,
+}
+
+impl ProofArguments {
+    /// Loads the [`AllowlistProof`] of the `signer`, if an allowlist proofs file was provided.
+    fn allowlist_proof(&self, signer: Address) -> anyhow::Result<Option<AllowlistProof>> {
+        let Some(path) = &self.allowlist_proofs else {
+            return Ok(None);
+        };
+
+        let mut proofs = read_json::<BTreeMap<Address, AllowlistProof>>(path)?;
+        let proof = proofs
+            .remove(&signer)
+            .with_context(|| format!("{} has no proof for {signer}", path.display()))?;
+
+        Ok(Some(proof))
+    }
+
+    /// Loads the [`BalanceProof`], if a balance proof file was provided.
+    fn balance_proof(&self) -> anyhow::Result<Option<BalanceProof>> {
+        self.balance_proof.as_deref().map(read_json).transpose()
+    }
+}
+
+/// The source of the Ethereum private key used to sign claims.
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct KeyArguments {
+    /// The private key, as a hexadecimal string.
+    #[arg(long, env = "AIRDROP_PRIVATE_KEY", hide_env_values = true)]
+    private_key: Option<String>,
+    /// An encrypted JSON keystore file with the private key.
+    #[arg(long)]
+    keystore: Option<PathBuf>,
+}
+
+impl KeyArguments {
+    /// Loads the [`SigningKey`], reading the keystore's password from the
+    /// `KEYSTORE_PASSWORD` environment variable.
+    fn signing_key(&self) -> anyhow::Result<SigningKey> {
+        let key_bytes = match (&self.private_key, &self.keystore) {
+            (Some(private_key), _) => {
+                hex::decode(private_key.trim_start_matches("0x")).context("Invalid private key")?
+            }
+            (None, Some(keystore)) => {
+                let password = std::env::var("KEYSTORE_PASSWORD")
+                    .context("Missing `KEYSTORE_PASSWORD` to decrypt the keystore")?;
+                eth_keystore::decrypt_key(keystore, password)
+                    .context("Failed to decrypt the keystore")?
+            }
+            (None, None) => unreachable!("Argument group requires a private key or a keystore"),
+        };
+
+        SigningKey::from_slice(&key_bytes).context("Invalid private key")
+    }
+}
+
+/// The representations of a signed claim.
+#[derive(Clone, Copy, ValueEnum)]
+enum Output {
+    /// The signature and the Ethereum address that created it.
+    Signature,
+    /// The BCS bytes of the `Claim` operation, as a hexadecimal string.
+    Operation,
+    /// The GraphQL mutation that submits the claim through the application's service.
+    Mutation,
+}
+
+fn main() -> anyhow::Result<()> {
+    match Cli::parse().command {
+        Command::Sign {
+            claim,
+            key,
+            proofs,
+            api_token,
+            output,
+        } => {
+            let signing_key = key.signing_key()?;
+            let signer = Address::from_private_key(&signing_key);
+            let signature = sign(&claim, &signing_key);
+
+            match output {
+                Output::Signature => {
+                    println!("signer: {signer}");
+                    println!("signature: {}", format_signature(&signature));
+                }
+                Output::Operation | Output::Mutation => {
+                    let signed_claim = AirDropClaim {
+                        campaign: CampaignId(claim.campaign),
+                        signature: signature.into(),
+                        destination: claim.destination(),
+                        api_token,
+                        relayer_fee: claim.relayer_fee,
+                        balance_proof: proofs.balance_proof()?,
+                        allowlist_proof: proofs.allowlist_proof(signer)?,
+                    };
+
+                    if let Output::Operation = output {
+                        let bytes = bcs::to_bytes(&Operation::Claim(signed_claim))
+                            .expect("`Operation` should be serializable");
+                        println!("{}", hex::encode(bytes));
+                    } else {
+                        println!("{}", claim_mutation(&signed_claim));
+                    }
+                }
+            }
+        }
+        Command::Verify {
+            claim,
+            signature,
+            expected_signer,
+        } => {
+            let signer = verify(&claim, &signature)?;
+
+            if let Some(expected_signer) = expected_signer {
+                ensure!(
+                    signer == expected_signer,
+                    "Claim was signed by {signer}, not by {expected_signer}"
+                );
+            }
+
+            println!("{signer}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Signs the `claim` with the `signing_key`.
+fn sign(claim: &ClaimArguments, signing_key: &SigningKey) -> Signature {
+    sign_claim(
+        signing_key,
+        claim.application_id(),
+        CampaignId(claim.campaign),
+        claim.destination(),
+        claim.relayer_fee,
+    )
+}
+
+/// Recovers the Ethereum address that signed the `claim` with the `signature`.
+fn verify(claim: &ClaimArguments, signature: &Signature) -> anyhow::Result<Address> {
+    recover_claim_signer(
+        signature,
+        claim.application_id(),
+        CampaignId(claim.campaign),
+        &claim.destination(),
+        claim.relayer_fee,
+    )
+    .context("Failed to recover the claim's signer")
+}
+
+/// Reads and deserializes a JSON file.
+fn read_json<T: DeserializeOwned>(path: &Path) -> anyhow::Result<T> {
+    let json =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    serde_json::from_str(&json).with_context(|| format!("Invalid JSON in {}", path.display()))
+}
+
+/// Formats a [`Signature`] as a hexadecimal string, prefixed with `0x`.
+fn format_signature(signature: &Signature) -> String {
+    format!("0x{}", hex::encode(signature.as_bytes()))
+}
+
+/// Builds the GraphQL `airDropClaim` mutation that submits a signed `claim`, with an argument
+/// for each of the claim's fields that is set.
+fn claim_mutation(claim: &AirDropClaim) -> String {
+    let async_graphql::Value::Object(fields) = claim.to_value() else {
+        unreachable!("`AirDropClaim` is an input object");
+    };
+
+    let arguments = fields
+        .into_iter()
+        .filter(|(_, value)| *value != async_graphql::Value::Null)
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("mutation {{ airDropClaim({arguments}) }}")
+}
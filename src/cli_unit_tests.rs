@@ -0,0 +1,220 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs, path::PathBuf};
+
+use airdrop_demo::{
+    allowlist::AllowlistProof, balance_proof::BalanceProof,
+    test_utils::create_dummy_application_id, AirDropClaim, CampaignId,
+};
+use alloy_primitives::{Address, B256};
+use async_graphql::{
+    parser::{
+        parse_query,
+        types::{DocumentOperations, Selection},
+    },
+    InputType,
+};
+use clap::Parser;
+use k256::ecdsa::SigningKey;
+use linera_sdk::{
+    abis::fungible::Account,
+    base::{AccountOwner, Amount, ChainId, CryptoHash, Owner},
+    serde_json,
+};
+use rand::rngs::OsRng;
+
+use super::{claim_mutation, sign, verify, ClaimArguments, Cli, Command, ProofArguments};
+
+/// Tests if the signer of a claim is recovered from the signature created by the tool.
+#[test]
+fn verifies_signed_claim() {
+    let signing_key = SigningKey::random(&mut OsRng);
+    let claim = create_claim_arguments(Some(Amount::from_tokens(1)));
+
+    let signature = sign(&claim, &signing_key);
+
+    assert_eq!(
+        verify(&claim, &signature).expect("Failed to recover signer"),
+        Address::from_private_key(&signing_key)
+    );
+}
+
+/// Tests if a signature doesn't verify as the signer's for a claim with a different relayer
+/// fee.
+#[test]
+fn signature_covers_relayer_fee() {
+    let signing_key = SigningKey::random(&mut OsRng);
+    let claim = create_claim_arguments(Some(Amount::from_tokens(1)));
+    let tampered_claim = create_claim_arguments(Some(Amount::from_tokens(2)));
+
+    let signature = sign(&claim, &signing_key);
+
+    assert_ne!(
+        verify(&tampered_claim, &signature).ok(),
+        Some(Address::from_private_key(&signing_key))
+    );
+}
+
+/// Tests if the mutation built for a claim with every field set parses back to the same claim,
+/// including an API token with characters that must be escaped.
+#[test]
+fn mutation_has_all_claim_fields() {
+    let claim = create_claim(
+        "token with \"quotes\" and \\ backslash",
+        Some(Amount::from_tokens(1)),
+        Some(BalanceProof {
+            snapshot_block: 100,
+            verifiable_result: vec![1, 2, 3],
+        }),
+        Some(AllowlistProof {
+            amount: Amount::from_tokens(10),
+            proof: vec![B256::repeat_byte(1).into(), B256::repeat_byte(2).into()],
+        }),
+    );
+
+    let mutation = claim_mutation(&claim);
+
+    assert_eq!(parse_claim_mutation(&mutation), claim);
+}
+
+/// Tests if the mutation built for a claim omits the fields that aren't set.
+#[test]
+fn mutation_omits_unset_fields() {
+    let claim = create_claim("", None, None, None);
+
+    let mutation = claim_mutation(&claim);
+
+    assert!(!mutation.contains("relayerFee"));
+    assert!(!mutation.contains("balanceProof"));
+    assert!(!mutation.contains("allowlistProof"));
+    assert_eq!(parse_claim_mutation(&mutation), claim);
+}
+
+/// Tests if the `sign` subcommand accepts the allowlist and balance proof files.
+#[test]
+fn sign_accepts_proof_files() {
+    let application_id = create_dummy_application_id("airdrop", 0);
+    let chain_id = ChainId(CryptoHash::test_hash("chain"));
+    let owner = AccountOwner::User(Owner(CryptoHash::test_hash("owner")));
+
+    let cli = Cli::try_parse_from([
+        "airdrop_demo_cli".to_owned(),
+        "sign".to_owned(),
+        format!("--application-id={application_id}"),
+        "--campaign=0".to_owned(),
+        format!("--chain-id={chain_id}"),
+        format!("--owner={owner}"),
+        "--private-key=0x01".to_owned(),
+        "--allowlist-proofs=proofs.json".to_owned(),
+        "--balance-proof=balance-proof.json".to_owned(),
+    ])
+    .expect("Failed to parse `sign` arguments");
+
+    let Command::Sign { proofs, .. } = cli.command else {
+        panic!("Arguments weren't parsed as the `sign` subcommand");
+    };
+
+    assert_eq!(proofs.allowlist_proofs, Some(PathBuf::from("proofs.json")));
+    assert_eq!(
+        proofs.balance_proof,
+        Some(PathBuf::from("balance-proof.json"))
+    );
+}
+
+/// Tests if the allowlist proof of the signer is read from a `proofs.json` file, and if a
+/// missing proof is reported.
+#[test]
+fn reads_signer_allowlist_proof() {
+    let signer = Address::repeat_byte(0xab);
+    let other_address = Address::repeat_byte(0xcd);
+    let proof = AllowlistProof {
+        amount: Amount::from_tokens(5),
+        proof: vec![B256::repeat_byte(3).into()],
+    };
+
+    let path = std::env::temp_dir().join(format!("airdrop-proofs-{}.json", std::process::id()));
+    let proofs_json = serde_json::json!({ signer.to_checksum(None): &proof });
+    fs::write(&path, proofs_json.to_string()).expect("Failed to write proofs file");
+
+    let arguments = ProofArguments {
+        allowlist_proofs: Some(path.clone()),
+        balance_proof: None,
+    };
+
+    let signer_proof = arguments.allowlist_proof(signer);
+    let other_proof = arguments.allowlist_proof(other_address);
+    fs::remove_file(&path).expect("Failed to remove proofs file");
+
+    assert_eq!(signer_proof.expect("Failed to read proof"), Some(proof));
+    assert!(other_proof.is_err());
+}
+
+/// Creates the [`ClaimArguments`] of a claim with an optional `relayer_fee`.
+fn create_claim_arguments(relayer_fee: Option<Amount>) -> ClaimArguments {
+    ClaimArguments {
+        application_id: create_dummy_application_id("airdrop", 0).forget_abi(),
+        campaign: 0,
+        chain_id: ChainId(CryptoHash::test_hash("chain")),
+        owner: AccountOwner::User(Owner(CryptoHash::test_hash("owner"))),
+        relayer_fee,
+    }
+}
+
+/// Creates an [`AirDropClaim`] signed by a random key.
+fn create_claim(
+    api_token: &str,
+    relayer_fee: Option<Amount>,
+    balance_proof: Option<BalanceProof>,
+    allowlist_proof: Option<AllowlistProof>,
+) -> AirDropClaim {
+    let arguments = create_claim_arguments(relayer_fee);
+    let signature = sign(&arguments, &SigningKey::random(&mut OsRng));
+
+    AirDropClaim {
+        campaign: CampaignId(arguments.campaign),
+        signature: signature.into(),
+        destination: Account {
+            chain_id: arguments.chain_id,
+            owner: arguments.owner,
+        },
+        api_token: api_token.to_owned(),
+        relayer_fee,
+        balance_proof,
+        allowlist_proof,
+    }
+}
+
+/// Parses a GraphQL `airDropClaim` `mutation`, returning the [`AirDropClaim`] described by its
+/// arguments.
+fn parse_claim_mutation(mutation: &str) -> AirDropClaim {
+    let document = parse_query(mutation).expect("Mutation should be valid GraphQL");
+    let DocumentOperations::Single(operation) = document.operations else {
+        panic!("Mutation should have a single operation");
+    };
+    let [selection] = operation.node.selection_set.node.items.as_slice() else {
+        panic!("Mutation should have a single field");
+    };
+    let Selection::Field(field) = &selection.node else {
+        panic!("Mutation should select a field");
+    };
+
+    assert_eq!(field.node.name.node, "airDropClaim");
+
+    let arguments = field
+        .node
+        .arguments
+        .iter()
+        .map(|(name, value)| {
+            let value = value
+                .node
+                .clone()
+                .into_const()
+                .expect("Mutation arguments should be constants");
+            (name.node.clone(), value)
+        })
+        .collect();
+
+    AirDropClaim::parse(Some(async_graphql::Value::Object(arguments)))
+        .expect("Mutation arguments should be a valid `AirDropClaim`")
+}
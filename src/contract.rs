@@ -7,17 +7,24 @@
 mod contract_unit_tests;
 mod state;
 
-use airdrop_demo::{AirDropClaim, AirDropId, Parameters};
-use alloy_primitives::Address;
+use airdrop_demo::{
+    allowlist::AllowlistProof,
+    balance_proof::ProvenBalance,
+    membership::{MembershipAbi, MembershipOperation},
+    AirDropClaim, AirDropId, Campaign, CampaignId, Criterion, DenylistEntry, Eligibility,
+    EligibilityEvidence, EligibilityRule, HoldingRule, LineraAirDropId, LineraIdentity, Operation,
+    Parameters,
+};
 use linera_sdk::{
-    abis::fungible::{self, Account},
-    base::{AccountOwner, Amount, WithContractAbi},
+    abis::fungible::{self, Account, FungibleTokenAbi},
+    base::{AccountOwner, Amount, ApplicationId, ChainId, Owner, WithContractAbi},
+    ensure,
     views::{RootView, View},
     Contract, ContractRuntime,
 };
 use serde::{Deserialize, Serialize};
 
-use self::state::Application;
+use self::state::{Application, Budget, ClaimRecord, Deposit, RejectedClaim, VestingPosition};
 
 pub struct ApplicationContract {
     state: Application,
@@ -31,7 +38,7 @@ impl WithContractAbi for ApplicationContract {
 }
 
 impl Contract for ApplicationContract {
-    type Message = ApprovedAirDrop;
+    type Message = Message;
     type Parameters = Parameters;
     type InstantiationArgument = ();
 
@@ -44,107 +51,1038 @@ impl Contract for ApplicationContract {
 
     /// Instantiates the application.
     ///
-    /// Fails if the [`Parameters`] specified to create the application are invalid.
+    /// Fails if the [`Parameters`] specified to create the application are invalid, or if the
+    /// token application doesn't exist or isn't one of the application's required applications.
     async fn instantiate(&mut self, _argument: Self::InstantiationArgument) {
-        // Fetch the parameters to check that they can be successfully deserialized.
-        let _parameters = self.runtime.application_parameters();
+        let parameters = self.runtime.application_parameters();
+
+        if let Err(error) = parameters.validate() {
+            panic!("Invalid application parameters: {error}");
+        }
+
+        self.check_token_application(parameters.token_id);
+    }
+
+    /// Executes an [`Operation`], forwarding the work that depends on the campaigns to the
+    /// application's creator chain.
+    async fn execute_operation(&mut self, operation: Self::Operation) -> Self::Response {
+        match operation {
+            Operation::CreateCampaign(campaign) => {
+                self.send_to_creator_chain(Message::CreateCampaign(campaign))
+                    .await
+            }
+            Operation::Claim(claim) => self.claim(claim).await,
+            Operation::ClaimBatch(claims) => self.claim_batch(claims).await,
+            Operation::LineraClaim {
+                campaign,
+                destination,
+            } => self.linera_claim(campaign, destination).await,
+            Operation::Fund { campaign, amount } => self.fund(campaign, amount).await,
+            Operation::Release { id } => self.send_to_creator_chain(Message::Release { id }).await,
+            Operation::UpdateDenylist { add, remove } => {
+                self.send_to_creator_chain(Message::UpdateDenylist { add, remove })
+                    .await
+            }
+        }
+    }
+
+    /// Handles a [`Message`] sent to the application's creator chain.
+    async fn execute_message(&mut self, message: Self::Message) {
+        self.handle_message(message).await;
+    }
+
+    async fn store(mut self) {
+        self.state.save().await.expect("Failed to save state");
+    }
+}
+
+impl ApplicationContract {
+    /// Handles a [`Message`] on the application's creator chain.
+    async fn handle_message(&mut self, message: Message) {
+        match message {
+            Message::CreateCampaign(campaign) => self.create_campaign(campaign).await,
+            Message::Claim(request) => self.approve_claim(request).await,
+            Message::ClaimBatch(requests) => self.approve_claim_batch(requests).await,
+            Message::LineraClaim(request) => self.approve_linera_claim(request).await,
+            Message::Release { id } => self.release(id).await,
+            Message::UpdateDenylist { add, remove } => self.update_denylist(add, remove),
+        }
     }
 
-    /// Verifies an [`AirDropClaim`][`zk_airdrop_demo::AirDropClaim`] and if approved, sends a
-    /// message to the application's creator chain to ask the tokens to be delivered.
-    async fn execute_operation(&mut self, claim: Self::Operation) -> Self::Response {
+    /// Sends a [`Message`] to the application's creator chain, or handles it immediately if the
+    /// current chain is the creator chain.
+    async fn send_to_creator_chain(&mut self, message: Message) {
         let creator_chain = self.runtime.application_creator_chain_id();
-        let amount = self.airdrop_amount(&claim).await;
+
+        if self.runtime.chain_id() == creator_chain {
+            self.handle_message(message).await;
+        } else {
+            self.runtime
+                .prepare_message(message)
+                .with_authentication()
+                .send_to(creator_chain);
+        }
+    }
+
+    /// Creates a new [`Campaign`], if requested by the application's admin.
+    async fn create_campaign(&mut self, campaign: Campaign) {
+        let admin = self.runtime.application_parameters().admin;
+
+        assert_eq!(
+            self.runtime.authenticated_signer(),
+            Some(admin),
+            "Only the admin can create campaigns"
+        );
+
+        if let Err(error) = campaign.validate() {
+            panic!("{error}");
+        }
+
+        self.check_linera_applications(&campaign.eligibility.rule);
+
+        let campaign_id = *self.state.next_campaign_id.get();
+
+        self.state.next_campaign_id.set(CampaignId(
+            campaign_id
+                .0
+                .checked_add(1)
+                .expect("Maximum number of campaigns reached"),
+        ));
+        self.state
+            .campaigns
+            .insert(&campaign_id, campaign)
+            .expect("Failed to write campaign to storage");
+    }
+
+    /// Checks that the token application exists and can be called, which requires it to be one
+    /// of the application's required applications, by reading the application's balance.
+    fn check_token_application(&mut self, token_id: ApplicationId<FungibleTokenAbi>) {
+        let owner = AccountOwner::Application(self.runtime.application_id().forget_abi());
+        let response =
+            self.runtime
+                .call_application(false, token_id, &fungible::Operation::Balance { owner });
+
+        assert!(
+            matches!(response, fungible::FungibleResponse::Balance(_)),
+            "Token application is not a fungible token"
+        );
+    }
+
+    /// Checks that the applications called by the Linera criteria of a `rule` exist and can be
+    /// called on this chain, so that claims don't fail after the campaign is created.
+    fn check_linera_applications(&mut self, rule: &EligibilityRule) {
+        match rule {
+            EligibilityRule::All(rules) | EligibilityRule::Any(rules) => {
+                for rule in rules {
+                    self.check_linera_applications(rule);
+                }
+            }
+            EligibilityRule::Not(rule) => self.check_linera_applications(rule),
+            EligibilityRule::Criterion(Criterion::LineraTokenBalance { token, .. }) => {
+                self.check_token_application(*token);
+            }
+            EligibilityRule::Criterion(Criterion::LineraApplicationMember { application }) => {
+                let identity = LineraIdentity::Chain(self.runtime.chain_id());
+                self.is_application_member(*application, identity);
+            }
+            EligibilityRule::Criterion(_) => {}
+        }
+    }
+
+    /// Adds and removes entries from the denylist, if requested by the application's admin.
+    fn update_denylist(&mut self, add: Vec<DenylistEntry>, remove: Vec<DenylistEntry>) {
+        let admin = self.runtime.application_parameters().admin;
+
+        assert_eq!(
+            self.runtime.authenticated_signer(),
+            Some(admin),
+            "Only the admin can update the denylist"
+        );
+
+        for entry in remove {
+            self.state
+                .denylist
+                .remove(&entry)
+                .expect("Failed to remove entry from the denylist");
+        }
+
+        for entry in add {
+            self.state
+                .denylist
+                .insert(&entry)
+                .expect("Failed to add entry to the denylist");
+        }
+    }
+
+    /// Verifies the signature of an [`AirDropClaim`] and sends it to the application's creator
+    /// chain, where it is checked against its campaign and paid if approved.
+    async fn claim(&mut self, claim: AirDropClaim) {
+        let request = self
+            .prepare_claim_request(claim)
+            .unwrap_or_else(|rejection| panic!("{rejection}"));
+
+        self.send_to_creator_chain(Message::Claim(request)).await;
+    }
+
+    /// Verifies the signatures of a batch of [`AirDropClaim`]s, and sends the ones with valid
+    /// signatures to the application's creator chain in a single message.
+    ///
+    /// Claims with invalid signatures or balance proofs are skipped, so that they don't prevent the
+    /// others from being paid.
+    async fn claim_batch(&mut self, claims: Vec<AirDropClaim>) {
+        let requests = claims
+            .into_iter()
+            .filter_map(|claim| self.prepare_claim_request(claim).ok())
+            .collect::<Vec<_>>();
+
+        if !requests.is_empty() {
+            self.send_to_creator_chain(Message::ClaimBatch(requests))
+                .await;
+        }
+    }
+
+    /// Sends a claim by the operation's authenticated signer, or by the current chain, to the
+    /// application's creator chain, where it is checked against the campaign's rule.
+    async fn linera_claim(&mut self, campaign: CampaignId, destination: Account) {
+        let request = LineraClaimRequest {
+            campaign,
+            owner: self.runtime.authenticated_signer(),
+            chain_id: self.runtime.chain_id(),
+            destination,
+        };
+
+        self.send_to_creator_chain(Message::LineraClaim(request))
+            .await;
+    }
+
+    /// Verifies the signature and the optional balance proof of an [`AirDropClaim`], and prepares
+    /// the [`ClaimRequest`] to be sent to the application's creator chain.
+    ///
+    /// Balance proofs are verified here, so that their verification runs in parallel on the
+    /// chains that submit the claims.
+    fn prepare_claim_request(
+        &mut self,
+        claim: AirDropClaim,
+    ) -> Result<ClaimRequest, ClaimRejection> {
         let application_id = self.runtime.application_id();
         let claimer = claim
             .signer_address(application_id)
-            .expect("Failed to verify signature");
+            .map_err(|_| ClaimRejection::InvalidSignature)?;
 
-        self.assert_eligibility(&claimer, &claim.api_token);
+        let proven_balance = match &claim.balance_proof {
+            Some(balance_proof) => {
+                let parameters = self.runtime.application_parameters();
+                let commitments = parameters
+                    .proof_of_sql_commitments
+                    .ok_or(ClaimRejection::BalanceProofsDisabled)?;
 
-        self.runtime
-            .prepare_message(ApprovedAirDrop {
-                id: claimer.into(),
+                Some(
+                    balance_proof
+                        .verify(&parameters.network, claimer, &commitments)
+                        .map_err(|_| ClaimRejection::InvalidBalanceProof)?,
+                )
+            }
+            None => None,
+        };
+
+        Ok(ClaimRequest {
+            id: AirDropId::new(claim.campaign, claimer),
+            destination: claim.destination,
+            api_token: claim.api_token,
+            relayer_fee: claim.relayer_fee,
+            proven_balance,
+            allowlist_proof: claim.allowlist_proof,
+        })
+    }
+
+    /// Checks a [`ClaimRequest`] against its campaign, and if it is approved, delivers its tokens
+    /// or starts vesting them.
+    ///
+    /// Rejected claims are recorded, so that the message is still handled on the creator chain.
+    async fn approve_claim(&mut self, request: ClaimRequest) {
+        let id = request.id;
+        let destination = request.destination;
+
+        if let Err(rejection) = self.try_approve_claim(request).await {
+            self.record_rejection(id, destination, rejection);
+        }
+    }
+
+    /// Checks each [`ClaimRequest`] of a batch independently, paying the approved claims.
+    ///
+    /// Rejected claims are recorded and skipped, so that they don't prevent the others from being
+    /// paid.
+    async fn approve_claim_batch(&mut self, requests: Vec<ClaimRequest>) {
+        for request in requests {
+            let id = request.id;
+            let destination = request.destination;
+
+            if let Err(rejection) = self.try_approve_claim(request).await {
+                self.record_rejection(id, destination, rejection);
+            }
+        }
+    }
+
+    /// Records a rejected claim together with the reason for its rejection.
+    fn record_rejection(&mut self, id: AirDropId, destination: Account, reason: ClaimRejection) {
+        self.state.rejected_claims.push(RejectedClaim {
+            id,
+            destination,
+            reason: reason.to_string(),
+            timestamp: self.runtime.system_time(),
+        });
+    }
+
+    /// Checks a [`ClaimRequest`] against its campaign, and if it is approved, delivers its tokens
+    /// or starts vesting them.
+    ///
+    /// The state is only changed if the claim is approved.
+    async fn try_approve_claim(&mut self, request: ClaimRequest) -> Result<(), ClaimRejection> {
+        let campaign_id = request.id.campaign();
+        let campaign = self
+            .state
+            .campaigns
+            .get(&campaign_id)
+            .await
+            .expect("Failed to read campaign from storage")
+            .ok_or(ClaimRejection::UnknownCampaign)?;
+
+        ensure!(
+            campaign.window.contains(self.runtime.system_time()),
+            ClaimRejection::OutsideWindow
+        );
+
+        let amount = match (campaign.amount.amount(), &request.allowlist_proof) {
+            (Some(amount), _) => amount,
+            (None, Some(allowlist_proof)) => allowlist_proof.amount,
+            (None, None) => return Err(ClaimRejection::MissingAllowlistProof),
+        };
+        let relayer_payment = match request.relayer_fee {
+            Some(fee) if fee > Amount::ZERO => {
+                ensure!(fee <= amount, ClaimRejection::RelayerFeeTooHigh);
+                let relayer = self
+                    .relayer_account()
+                    .ok_or(ClaimRejection::MissingRelayer)?;
+                Some((fee, relayer))
+            }
+            _ => None,
+        };
+
+        self.check_denylist(
+            &request.id,
+            &request.destination,
+            relayer_payment.as_ref().map(|(_, relayer)| relayer),
+        )
+        .await?;
+        ensure!(
+            !self
+                .state
+                .handled_airdrops
+                .contains_key(&request.id)
+                .await
+                .expect("Failed to read handled claims from storage"),
+            ClaimRejection::AlreadyPaid
+        );
+
+        let evidence = match (request.proven_balance, &request.allowlist_proof) {
+            (Some(proven_balance), _) => {
+                Self::check_proven_balance(&campaign.eligibility, proven_balance, amount)?
+            }
+            (None, Some(allowlist_proof)) => {
+                Self::check_allowlist_proof(&campaign.eligibility, &request.id, allowlist_proof)?
+            }
+            (None, None) => self.check_eligibility(&request.id, &request.api_token)?,
+        };
+
+        ensure!(
+            evidence.amount == amount,
+            ClaimRejection::EligibilityCheckFailed
+        );
+
+        self.charge_budget(campaign_id, amount).await?;
+
+        let record = ClaimRecord {
+            destination: request.destination,
+            amount,
+            relayer_fee: relayer_payment.as_ref().map(|(fee, _)| *fee),
+            approved_at: self.runtime.system_time(),
+            evidence,
+        };
+
+        self.state
+            .handled_airdrops
+            .insert(&request.id, record)
+            .expect("Failed to write handled claim to storage");
+
+        let mut payout = amount;
+
+        if let Some((fee, relayer)) = relayer_payment {
+            payout = payout.saturating_sub(fee);
+            self.deliver(fee, relayer);
+        }
+
+        if campaign.vesting.is_some() {
+            let position = VestingPosition {
+                destination: request.destination,
+                total: payout,
+                released: Amount::ZERO,
+                start: self.runtime.system_time(),
+            };
+
+            self.state
+                .vesting_positions
+                .insert(&request.id, position)
+                .expect("Failed to write vesting position to storage");
+        } else {
+            self.deliver(payout, request.destination);
+        }
+
+        Ok(())
+    }
+
+    /// Checks a [`LineraClaimRequest`] against its campaign's rule on Linera identities, and if it
+    /// is approved, delivers its tokens.
+    ///
+    /// Rejected claims abort the execution.
+    async fn approve_linera_claim(&mut self, request: LineraClaimRequest) {
+        if let Err(rejection) = self.try_approve_linera_claim(request).await {
+            panic!("{rejection}");
+        }
+    }
+
+    /// Checks a [`LineraClaimRequest`] against its campaign's rule on Linera identities, and if it
+    /// is approved, delivers its tokens.
+    ///
+    /// The claimer is the owner who signed the claim if they satisfy the rule, or else the chain
+    /// the claim was submitted from. The state is only changed if the claim is approved.
+    async fn try_approve_linera_claim(
+        &mut self,
+        request: LineraClaimRequest,
+    ) -> Result<(), ClaimRejection> {
+        let campaign = self
+            .state
+            .campaigns
+            .get(&request.campaign)
+            .await
+            .expect("Failed to read campaign from storage")
+            .ok_or(ClaimRejection::UnknownCampaign)?;
+
+        ensure!(
+            campaign.window.contains(self.runtime.system_time()),
+            ClaimRejection::OutsideWindow
+        );
+
+        let rule = &campaign.eligibility.rule;
+
+        ensure!(
+            rule.has_linera_criteria(),
+            ClaimRejection::UnsupportedLineraClaim
+        );
+
+        let amount = campaign
+            .amount
+            .amount()
+            .ok_or(ClaimRejection::UnsupportedLineraClaim)?;
+
+        let claimer = request
+            .owner
+            .map(LineraIdentity::Owner)
+            .into_iter()
+            .chain([LineraIdentity::Chain(request.chain_id)])
+            .find(|identity| self.evaluate_linera_rule(rule, *identity))
+            .ok_or(ClaimRejection::Ineligible)?;
+        let id = LineraAirDropId {
+            campaign: request.campaign,
+            claimer,
+        };
+
+        ensure!(
+            !self.is_denied(claimer.into()).await,
+            ClaimRejection::DeniedClaimer
+        );
+        self.check_accounts_denylist(&request.destination, None)
+            .await?;
+        ensure!(
+            !self
+                .state
+                .linera_airdrops
+                .contains_key(&id)
+                .await
+                .expect("Failed to read handled claims from storage"),
+            ClaimRejection::AlreadyPaid
+        );
+
+        self.charge_budget(request.campaign, amount).await?;
+
+        let record = ClaimRecord {
+            destination: request.destination,
+            amount,
+            relayer_fee: None,
+            approved_at: self.runtime.system_time(),
+            evidence: EligibilityEvidence {
+                eligible: true,
+                balance: None,
+                block: campaign.eligibility.snapshot_block,
+                matched_rule: Some(campaign.eligibility.rule.to_string()),
                 amount,
-                destination: claim.destination,
-            })
-            .with_authentication()
-            .send_to(creator_chain);
+            },
+        };
+
+        self.state
+            .linera_airdrops
+            .insert(&id, record)
+            .expect("Failed to write handled claim to storage");
+
+        self.deliver(amount, request.destination);
+
+        Ok(())
+    }
+
+    /// Evaluates a `rule` on Linera identities for an `identity`, calling the applications of
+    /// its criteria on this chain.
+    fn evaluate_linera_rule(&mut self, rule: &EligibilityRule, identity: LineraIdentity) -> bool {
+        match rule {
+            EligibilityRule::Criterion(criterion) => {
+                self.evaluate_linera_criterion(criterion, identity)
+            }
+            EligibilityRule::All(rules) => rules
+                .iter()
+                .all(|rule| self.evaluate_linera_rule(rule, identity)),
+            EligibilityRule::Any(rules) => rules
+                .iter()
+                .any(|rule| self.evaluate_linera_rule(rule, identity)),
+            EligibilityRule::Not(rule) => !self.evaluate_linera_rule(rule, identity),
+        }
+    }
+
+    /// Evaluates a single `criterion` on Linera identities for an `identity`.
+    ///
+    /// Token balances are only held by users, so chains never satisfy them. Criteria on Ethereum
+    /// addresses are never satisfied by Linera identities.
+    fn evaluate_linera_criterion(
+        &mut self,
+        criterion: &Criterion,
+        identity: LineraIdentity,
+    ) -> bool {
+        match (criterion, identity) {
+            (Criterion::LineraMember { members }, _) => members.contains(&identity),
+            (Criterion::LineraTokenBalance { token, minimum }, LineraIdentity::Owner(owner)) => {
+                self.token_balance(*token, AccountOwner::User(owner)) >= *minimum
+            }
+            (Criterion::LineraTokenBalance { .. }, LineraIdentity::Chain(_)) => false,
+            (Criterion::LineraApplicationMember { application }, _) => {
+                self.is_application_member(*application, identity)
+            }
+            (_, _) => false,
+        }
+    }
+
+    /// Reads the balance of an `owner` from a fungible `token` application on this chain.
+    fn token_balance(
+        &mut self,
+        token: ApplicationId<FungibleTokenAbi>,
+        owner: AccountOwner,
+    ) -> Amount {
+        let response =
+            self.runtime
+                .call_application(false, token, &fungible::Operation::Balance { owner });
+
+        match response {
+            fungible::FungibleResponse::Balance(balance) => balance,
+            _ => panic!("Token application is not a fungible token"),
+        }
+    }
+
+    /// Asks a [`MembershipAbi`] `application` on this chain if an `identity` is one of its
+    /// members.
+    fn is_application_member(
+        &mut self,
+        application: ApplicationId<MembershipAbi>,
+        identity: LineraIdentity,
+    ) -> bool {
+        self.runtime.call_application(
+            false,
+            application,
+            &MembershipOperation::IsMember { identity },
+        )
+    }
+
+    /// Adds an `amount` to what a campaign has paid, if its budget has enough remaining tokens.
+    async fn charge_budget(
+        &mut self,
+        campaign: CampaignId,
+        amount: Amount,
+    ) -> Result<(), ClaimRejection> {
+        let mut budget = self.budget(campaign).await;
+
+        ensure!(
+            budget.remaining_amount() >= amount,
+            ClaimRejection::BudgetExhausted
+        );
+
+        budget.paid = budget.paid.saturating_add(amount);
+
+        self.state
+            .budgets
+            .insert(&campaign, budget)
+            .expect("Failed to write campaign budget to storage");
+
+        Ok(())
+    }
+
+    /// Returns the [`Account`] of the relayer that submitted the claim being handled, which
+    /// receives the claim's relayer fee.
+    ///
+    /// The relayer is the authenticated signer of the claim operation, on the chain where it
+    /// was submitted.
+    fn relayer_account(&mut self) -> Option<Account> {
+        let owner = self.runtime.authenticated_signer()?;
+        let chain_id = self
+            .runtime
+            .message_id()
+            .map_or_else(|| self.runtime.chain_id(), |message_id| message_id.chain_id);
+
+        Some(Account {
+            chain_id,
+            owner: AccountOwner::User(owner),
+        })
     }
 
-    /// Checks that an `airdrop` hasn't been handled before, and if so delivers its tokens.
-    async fn execute_message(&mut self, airdrop: Self::Message) {
-        self.track_claim(&airdrop.id).await;
+    /// Delivers the tokens of an airdrop that have vested but haven't been released yet.
+    ///
+    /// Only the chain where the tokens are delivered to is allowed to request their release.
+    async fn release(&mut self, id: AirDropId) {
+        let requester = self
+            .runtime
+            .message_id()
+            .map_or_else(|| self.runtime.chain_id(), |message_id| message_id.chain_id);
+
+        let vesting = self
+            .campaign(id.campaign())
+            .await
+            .vesting
+            .expect("Airdrop tokens are not vested");
+
+        let mut position = self
+            .state
+            .vesting_positions
+            .get(&id)
+            .await
+            .expect("Failed to read vesting position from storage")
+            .expect("Airdrop has no vesting position");
+
+        assert_eq!(
+            requester, position.destination.chain_id,
+            "Vested tokens can only be released by their destination chain"
+        );
+
+        if let Err(rejection) = self.check_denylist(&id, &position.destination, None).await {
+            panic!("{rejection}");
+        }
+
+        let now = self.runtime.system_time();
+        let vested = vesting.vested_amount(position.total, position.start, now);
+        let releasable = vested.saturating_sub(position.released);
 
+        if releasable == Amount::ZERO {
+            return;
+        }
+
+        position.released = vested;
+        self.deliver(releasable, position.destination);
+
+        self.state
+            .vesting_positions
+            .insert(&id, position)
+            .expect("Failed to write vesting position to storage");
+    }
+
+    /// Transfers `amount` tokens from the application's account to a `destination` account.
+    fn deliver(&mut self, amount: Amount, destination: Account) {
         let parameters = self.runtime.application_parameters();
         let source_account = AccountOwner::Application(self.runtime.application_id().forget_abi());
 
         let transfer = fungible::Operation::Transfer {
             owner: source_account,
-            amount: airdrop.amount,
-            target_account: airdrop.destination,
+            amount,
+            target_account: destination,
         };
 
         self.runtime
             .call_application(true, parameters.token_id, &transfer);
     }
 
-    async fn store(mut self) {
-        self.state.save().await.expect("Failed to save state");
-    }
-}
+    /// Transfers `amount` tokens from the authenticated signer into the application's account,
+    /// and records the deposit into the `campaign`'s budget.
+    ///
+    /// Only accepted on the creator chain, where the campaign is checked to exist before any
+    /// tokens are transferred, so that they can't be stranded in the application's account.
+    async fn fund(&mut self, campaign: CampaignId, amount: Amount) {
+        let creator_chain = self.runtime.application_creator_chain_id();
 
-impl ApplicationContract {
-    /// Asserts that an [`Address`] is eligible for an airdrop.
-    pub fn assert_eligibility(&mut self, address: &Address, api_token: &str) {
-        let request = async_graphql::Request::new(format!(
-            r#"query {{ checkEligibility(address: "{address}", apiToken: "{api_token}") }}"#
-        ));
+        assert_eq!(
+            self.runtime.chain_id(),
+            creator_chain,
+            "Campaigns can only be funded on the application's creator chain"
+        );
 
-        let application_id = self.runtime.application_id();
-        let response = self.runtime.query_service(application_id, request);
+        self.campaign(campaign).await;
 
-        let async_graphql::Value::Object(data_object) = response.data else {
-            panic!("Unexpected response from `checkEligibility: {response:?}`");
+        let funder = AccountOwner::User(
+            self.runtime
+                .authenticated_signer()
+                .expect("Funding the airdrop requires an authenticated signer"),
+        );
+        let parameters = self.runtime.application_parameters();
+
+        let transfer = fungible::Operation::Transfer {
+            owner: funder,
+            amount,
+            target_account: Account {
+                chain_id: creator_chain,
+                owner: AccountOwner::Application(self.runtime.application_id().forget_abi()),
+            },
         };
 
-        let async_graphql::Value::Boolean(is_eligible) = data_object["checkEligibility"] else {
-            panic!("Missing `checkEligibility` result in response data: {data_object:?}");
+        self.runtime
+            .call_application(true, parameters.token_id, &transfer);
+
+        let deposit = Deposit {
+            campaign,
+            funder,
+            amount,
+            timestamp: self.runtime.system_time(),
         };
 
-        assert!(is_eligible);
+        self.record_deposit(deposit).await;
     }
 
-    /// Calculates the [`Amount`] to be airdropped for one [`AirDropClaim`].
-    async fn airdrop_amount(&mut self, _claim: &AirDropClaim) -> Amount {
-        Amount::ONE
+    /// Records a [`Deposit`] in the application's funding history and in its campaign's budget.
+    async fn record_deposit(&mut self, deposit: Deposit) {
+        let mut budget = self.budget(deposit.campaign).await;
+
+        budget.funded = budget.funded.saturating_add(deposit.amount);
+
+        self.state
+            .budgets
+            .insert(&deposit.campaign, budget)
+            .expect("Failed to write campaign budget to storage");
+
+        let funded_so_far = self
+            .state
+            .funders
+            .get(&deposit.funder)
+            .await
+            .expect("Failed to read funder's deposits from storage")
+            .unwrap_or_default();
+
+        self.state
+            .funders
+            .insert(
+                &deposit.funder,
+                funded_so_far.saturating_add(deposit.amount),
+            )
+            .expect("Failed to write funder's deposits to storage");
+
+        self.state.deposits.push(deposit);
     }
 
-    /// Tracks a claim, aborting the execution if it has already been handled.
-    async fn track_claim(&mut self, airdrop: &AirDropId) {
-        assert!(
+    /// Returns the current [`Budget`] of a campaign.
+    async fn budget(&mut self, campaign: CampaignId) -> Budget {
+        self.state
+            .budgets
+            .get(&campaign)
+            .await
+            .expect("Failed to read campaign budget from storage")
+            .unwrap_or_default()
+    }
+
+    /// Returns the configuration of a [`Campaign`], aborting the execution if it doesn't exist.
+    async fn campaign(&mut self, campaign: CampaignId) -> Campaign {
+        self.state
+            .campaigns
+            .get(&campaign)
+            .await
+            .expect("Failed to read campaign from storage")
+            .expect("Unknown campaign")
+    }
+
+    /// Checks that neither the claimer's Ethereum address, nor the `destination` account, nor
+    /// the `relayer` account that receives a fee are in the denylist.
+    async fn check_denylist(
+        &mut self,
+        airdrop: &AirDropId,
+        destination: &Account,
+        relayer: Option<&Account>,
+    ) -> Result<(), ClaimRejection> {
+        ensure!(
             !self
-                .state
-                .handled_airdrops
-                .contains(airdrop)
+                .is_denied(DenylistEntry::ExternalAddress(
+                    airdrop.external_address().into()
+                ))
+                .await,
+            ClaimRejection::DeniedAddress
+        );
+
+        self.check_accounts_denylist(destination, relayer).await
+    }
+
+    /// Checks that neither the `destination` account nor the `relayer` account that receives a
+    /// fee are in the denylist.
+    async fn check_accounts_denylist(
+        &mut self,
+        destination: &Account,
+        relayer: Option<&Account>,
+    ) -> Result<(), ClaimRejection> {
+        ensure!(
+            !self
+                .is_denied(DenylistEntry::Chain(destination.chain_id))
                 .await
-                .expect("Failed to read handled claims from storage"),
-            "Airdrop has already been paid"
+                && !self
+                    .is_denied(DenylistEntry::Owner(destination.owner))
+                    .await,
+            ClaimRejection::DeniedDestination
         );
 
+        if let Some(relayer) = relayer {
+            ensure!(
+                !self.is_denied(DenylistEntry::Chain(relayer.chain_id)).await
+                    && !self.is_denied(DenylistEntry::Owner(relayer.owner)).await,
+                ClaimRejection::DeniedRelayer
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the `entry` is in the denylist.
+    async fn is_denied(&self, entry: DenylistEntry) -> bool {
         self.state
-            .handled_airdrops
-            .insert(airdrop)
-            .expect("Failed to write handled claim to storage");
+            .denylist
+            .contains(&entry)
+            .await
+            .expect("Failed to read the denylist from storage")
+    }
+
+    /// Checks if a balance proven with Proof-of-SQL meets a campaign's [`Eligibility`]
+    /// criteria, returning the [`EligibilityEvidence`] of the claimer eligible for the `amount`.
+    fn check_proven_balance(
+        eligibility: &Eligibility,
+        proven_balance: ProvenBalance,
+        amount: Amount,
+    ) -> Result<EligibilityEvidence, ClaimRejection> {
+        let EligibilityRule::Criterion(Criterion::Balance {
+            minimum,
+            holding: HoldingRule::AtSnapshot,
+        }) = eligibility.rule
+        else {
+            return Err(ClaimRejection::UnsupportedBalanceProof);
+        };
+
+        ensure!(
+            proven_balance.snapshot_block == eligibility.snapshot_block,
+            ClaimRejection::WrongSnapshotBlock
+        );
+        ensure!(
+            proven_balance.balance >= minimum,
+            ClaimRejection::Ineligible
+        );
+
+        Ok(EligibilityEvidence {
+            eligible: true,
+            balance: Some(proven_balance.balance),
+            block: proven_balance.snapshot_block,
+            matched_rule: Some(eligibility.rule.to_string()),
+            amount,
+        })
+    }
+
+    /// Checks if the Ethereum address of an [`AirDropId`] is in a campaign's allowlist,
+    /// returning the [`EligibilityEvidence`] of the claimer eligible for the allowlisted amount.
+    fn check_allowlist_proof(
+        eligibility: &Eligibility,
+        airdrop: &AirDropId,
+        allowlist_proof: &AllowlistProof,
+    ) -> Result<EligibilityEvidence, ClaimRejection> {
+        let EligibilityRule::Criterion(Criterion::Allowlisted { root }) = eligibility.rule else {
+            return Err(ClaimRejection::UnsupportedAllowlistProof);
+        };
+
+        ensure!(
+            allowlist_proof.verify(root, airdrop.external_address()),
+            ClaimRejection::InvalidAllowlistProof
+        );
+
+        Ok(EligibilityEvidence {
+            eligible: true,
+            balance: None,
+            block: eligibility.snapshot_block,
+            matched_rule: Some(eligibility.rule.to_string()),
+            amount: allowlist_proof.amount,
+        })
+    }
+
+    /// Checks if the Ethereum address of an [`AirDropId`] is eligible for its campaign's
+    /// airdrop, returning the [`EligibilityEvidence`] reported by the service.
+    fn check_eligibility(
+        &mut self,
+        airdrop: &AirDropId,
+        api_token: &str,
+    ) -> Result<EligibilityEvidence, ClaimRejection> {
+        let variables = async_graphql::Variables::from_value(async_graphql::value!({
+            "campaign": airdrop.campaign().0,
+            "address": airdrop.external_address().to_string(),
+            "apiToken": api_token,
+        }));
+        let request = async_graphql::Request::new(
+            "query CheckEligibility(\
+                $campaign: CampaignId!, \
+                $address: EthereumAddress!, \
+                $apiToken: String!\
+            ) { \
+                checkEligibility(campaign: $campaign, address: $address, apiToken: $apiToken) { \
+                    eligible \
+                    balance \
+                    block \
+                    matched_rule: matchedRule \
+                    amount \
+                } \
+            }",
+        )
+        .variables(variables);
+
+        let application_id = self.runtime.application_id();
+        let response = self.runtime.query_service(application_id, request);
+
+        let async_graphql::Value::Object(data_object) = response.data else {
+            return Err(ClaimRejection::EligibilityCheckFailed);
+        };
+
+        let evidence = data_object
+            .get("checkEligibility")
+            .cloned()
+            .and_then(|value| async_graphql::from_value::<EligibilityEvidence>(value).ok())
+            .ok_or(ClaimRejection::EligibilityCheckFailed)?;
+
+        ensure!(evidence.eligible, ClaimRejection::Ineligible);
+
+        Ok(evidence)
     }
 }
 
-/// An airdrop claim that has been approved and sent back to the creator chain to deliver the
-/// tokens.
+/// Messages sent to the application's creator chain.
 #[derive(Debug, Deserialize, Serialize)]
 #[cfg_attr(test, derive(Clone, Eq, PartialEq))]
-pub struct ApprovedAirDrop {
+pub enum Message {
+    /// A request from the admin to create a new campaign.
+    CreateCampaign(Campaign),
+    /// A claim to be checked against its campaign and paid if approved.
+    Claim(ClaimRequest),
+    /// A batch of claims with verified signatures, to be checked and paid independently of each
+    /// other.
+    ClaimBatch(Vec<ClaimRequest>),
+    /// A claim by a Linera identity, to be checked against its campaign's rule on Linera
+    /// identities.
+    LineraClaim(LineraClaimRequest),
+    /// A request to release the vested tokens of an airdrop.
+    Release { id: AirDropId },
+    /// A request from the admin to update the denylist.
+    UpdateDenylist {
+        add: Vec<DenylistEntry>,
+        remove: Vec<DenylistEntry>,
+    },
+}
+
+/// An airdrop claim with a verified signature, sent to the creator chain to be checked against
+/// its campaign.
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(test, derive(Clone, Eq, PartialEq))]
+pub struct ClaimRequest {
     id: AirDropId,
-    amount: Amount,
     destination: Account,
+    api_token: String,
+    relayer_fee: Option<Amount>,
+    proven_balance: Option<ProvenBalance>,
+    allowlist_proof: Option<AllowlistProof>,
+}
+
+/// An airdrop claim by a Linera identity, sent to the creator chain to be checked against its
+/// campaign's rule on Linera identities.
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(test, derive(Clone, Eq, PartialEq))]
+pub struct LineraClaimRequest {
+    campaign: CampaignId,
+    owner: Option<Owner>,
+    chain_id: ChainId,
+    destination: Account,
+}
+
+/// The reasons for rejecting a [`ClaimRequest`] or a [`LineraClaimRequest`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClaimRejection {
+    InvalidSignature,
+    InvalidBalanceProof,
+    BalanceProofsDisabled,
+    WrongSnapshotBlock,
+    UnsupportedBalanceProof,
+    MissingAllowlistProof,
+    InvalidAllowlistProof,
+    UnsupportedAllowlistProof,
+    UnsupportedLineraClaim,
+    UnknownCampaign,
+    OutsideWindow,
+    AlreadyPaid,
+    Ineligible,
+    EligibilityCheckFailed,
+    BudgetExhausted,
+    RelayerFeeTooHigh,
+    MissingRelayer,
+    DeniedAddress,
+    DeniedClaimer,
+    DeniedDestination,
+    DeniedRelayer,
+}
+
+impl std::fmt::Display for ClaimRejection {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let reason = match self {
+            ClaimRejection::InvalidSignature => "Failed to verify signature",
+            ClaimRejection::InvalidBalanceProof => "Failed to verify balance proof",
+            ClaimRejection::BalanceProofsDisabled => {
+                "Balance proofs are not enabled for this application"
+            }
+            ClaimRejection::WrongSnapshotBlock => {
+                "Balance proof is not for the campaign's snapshot block"
+            }
+            ClaimRejection::UnsupportedBalanceProof => {
+                "Balance proofs are only accepted for campaigns that only check the snapshot balance"
+            }
+            ClaimRejection::MissingAllowlistProof => {
+                "Campaign requires a proof that the claimer is in its allowlist"
+            }
+            ClaimRejection::InvalidAllowlistProof => "Failed to verify allowlist proof",
+            ClaimRejection::UnsupportedAllowlistProof => {
+                "Allowlist proofs are only accepted for campaigns with an allowlist"
+            }
+            ClaimRejection::UnsupportedLineraClaim => {
+                "Linera claims are only accepted for campaigns with rules on Linera identities"
+            }
+            ClaimRejection::UnknownCampaign => "Unknown campaign",
+            ClaimRejection::OutsideWindow => "Campaign is not accepting claims",
+            ClaimRejection::AlreadyPaid => "Airdrop has already been paid",
+            ClaimRejection::Ineligible => "Claimer is not eligible for the airdrop",
+            ClaimRejection::EligibilityCheckFailed => "Failed to check the claimer's eligibility",
+            ClaimRejection::BudgetExhausted => "Campaign budget is exhausted",
+            ClaimRejection::RelayerFeeTooHigh => "Relayer fee exceeds the airdropped amount",
+            ClaimRejection::MissingRelayer => {
+                "Claims with a relayer fee must be submitted by an authenticated signer"
+            }
+            ClaimRejection::DeniedAddress => "Claimer address is in the denylist",
+            ClaimRejection::DeniedClaimer => "Claimer is in the denylist",
+            ClaimRejection::DeniedDestination => "Destination account is in the denylist",
+            ClaimRejection::DeniedRelayer => "Relayer account is in the denylist",
+        };
+
+        formatter.write_str(reason)
+    }
 }
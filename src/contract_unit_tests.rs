@@ -1,16 +1,31 @@
 // Copyright (c) Zefchain Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+};
+
 use airdrop_demo::{
+    allowlist::AllowlistTree,
+    balance_proof::{balance_query, BalanceProof, ProvenBalance},
+    gateway::GatewayConfiguration,
+    membership::{MembershipAbi, MembershipOperation},
+    network::Network,
     test_utils::{create_dummy_application_id, create_dummy_token_id, sign_claim},
-    AirDropClaim, AirDropId, ApplicationAbi, Parameters,
+    AirDropClaim, AirDropId, AmountPolicy, ApplicationAbi, Campaign, CampaignId, ClaimWindow,
+    Criterion, DenylistEntry, Eligibility, EligibilityEvidence, EligibilityRule, HoldingRule,
+    LineraAirDropId, LineraIdentity, Operation, Parameters, VestingSchedule,
 };
 use alloy_primitives::{Address, U256};
 use indexmap::IndexMap;
 use k256::ecdsa::SigningKey;
 use linera_sdk::{
-    abis::fungible::{self, Account, FungibleResponse},
-    base::{AccountOwner, Amount, ApplicationId, ChainId, CryptoHash, Destination, Owner},
+    abis::fungible::{self, Account, FungibleResponse, FungibleTokenAbi},
+    base::{
+        AccountOwner, Amount, ApplicationId, BlockHeight, ChainId, CryptoHash, Destination,
+        MessageId, Owner, TimeDelta, Timestamp,
+    },
     bcs,
     util::BlockingWait,
     views::View,
@@ -18,39 +33,171 @@ use linera_sdk::{
 };
 use rand::rngs::OsRng;
 
-use super::{state::Application, ApplicationContract, ApprovedAirDrop};
+use super::{
+    state::{Application, Budget, ClaimRecord, Deposit, RejectedClaim, VestingPosition},
+    ApplicationContract, ClaimRequest, LineraClaimRequest, Message,
+};
+
+/// Tests if the admin can create campaigns, and if they receive sequential IDs.
+#[test]
+fn admin_creates_campaigns() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let first_campaign = create_dummy_campaign(None);
+    let second_campaign = Campaign {
+        amount: AmountPolicy::Fixed(Amount::from_tokens(3)),
+        ..create_dummy_campaign(None)
+    };
+
+    contract.runtime.set_authenticated_signer(admin());
+
+    let () = contract
+        .execute_operation(Operation::CreateCampaign(first_campaign.clone()))
+        .blocking_wait();
+    let () = contract
+        .execute_operation(Operation::CreateCampaign(second_campaign.clone()))
+        .blocking_wait();
+
+    let read_campaign = |contract: &ApplicationContract, id| {
+        contract
+            .state
+            .campaigns
+            .get(&CampaignId(id))
+            .blocking_wait()
+            .expect("Failed to read campaign")
+    };
+
+    assert_eq!(read_campaign(&contract, 0), Some(first_campaign));
+    assert_eq!(read_campaign(&contract, 1), Some(second_campaign));
+    assert_eq!(*contract.state.next_campaign_id.get(), CampaignId(2));
+}
+
+/// Tests if the application can't be created with a Gateway URL that isn't an HTTP URL.
+#[test]
+#[should_panic(
+    expected = "Invalid application parameters: Gateway URL must be an HTTP or HTTPS URL"
+)]
+fn rejects_invalid_gateway_url() {
+    let mut parameters = create_parameters();
+    parameters.gateway.url = "api.spaceandtime.dev/v1/sql".to_owned();
+
+    create_and_instantiate_contract_with(parameters);
+}
+
+/// Tests if the application can't be created with a holding query template that doesn't use the
+/// holding period's start block.
+#[test]
+#[should_panic(
+    expected = "Invalid application parameters: Gateway's `holding_query_template` is invalid: \
+    Query template is missing the `{start_block}` placeholder"
+)]
+fn rejects_holding_query_template_without_start_block() {
+    let mut parameters = create_parameters();
+    parameters.gateway.holding_query_template = parameters.gateway.query_template.clone();
+
+    create_and_instantiate_contract_with(parameters);
+}
+
+/// Tests if the application can't be created with a token balance query template that doesn't
+/// use the token's contract.
+#[test]
+#[should_panic(
+    expected = "Invalid application parameters: Gateway's `token_balance_query_template` is \
+    invalid: Query template is missing the `{contract}` placeholder"
+)]
+fn rejects_token_balance_query_template_without_contract() {
+    let mut parameters = create_parameters();
+    parameters.gateway.token_balance_query_template = parameters.gateway.query_template.clone();
+
+    create_and_instantiate_contract_with(parameters);
+}
+
+/// Tests if the application can't be created with a token application that isn't a fungible
+/// token.
+#[test]
+#[should_panic(expected = "Token application is not a fungible token")]
+fn rejects_token_application_without_balances() {
+    let mut runtime = ContractRuntime::new()
+        .with_application_parameters(create_parameters())
+        .with_application_id(create_dummy_application_id("zk-airdrop", 1));
+
+    runtime.set_call_application_handler(|_, _, _| {
+        bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+    });
+
+    let mut contract = ApplicationContract {
+        state: Application::load(runtime.root_view_storage_context())
+            .blocking_wait()
+            .expect("Failed to read from mock key value store"),
+        runtime,
+    };
+
+    contract.instantiate(()).blocking_wait();
+}
+
+/// Tests if a campaign with balance criteria that any address meets is rejected.
+#[test]
+#[should_panic(expected = "Balance criteria must have a non-zero minimum")]
+fn rejects_campaign_with_zero_minimum_balance() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let mut campaign = create_dummy_campaign(None);
+    campaign.eligibility.rule = EligibilityRule::minimum_balance(U256::ZERO);
+
+    contract.runtime.set_authenticated_signer(admin());
+
+    let () = contract
+        .execute_operation(Operation::CreateCampaign(campaign))
+        .blocking_wait();
+}
+
+/// Tests if a campaign creation requested by someone other than the admin is rejected.
+#[test]
+#[should_panic(expected = "Only the admin can create campaigns")]
+fn rejects_campaign_from_non_admin() {
+    let (mut contract, _) = create_and_instantiate_contract();
+
+    contract
+        .runtime
+        .set_authenticated_signer(Owner(CryptoHash::test_hash("impostor")));
+
+    let () = contract
+        .execute_operation(Operation::CreateCampaign(create_dummy_campaign(None)))
+        .blocking_wait();
+}
 
-/// Tests if a valid airdrop claim is accepted and results in a message to execute the payment.
+/// Tests if a claim with a valid signature is forwarded to the creator chain to be checked and
+/// paid.
 #[test]
-fn accepts_new_claim() {
+fn forwards_new_claim() {
     let (mut contract, application_id) = create_and_instantiate_contract();
     let signing_key = SigningKey::random(&mut OsRng);
     let external_address = Address::from_private_key(&signing_key);
     let destination_account = create_dummy_destination(0);
-    let signature = sign_claim(&signing_key, application_id, destination_account);
+    let campaign = CampaignId(3);
+    let signature = sign_claim(
+        &signing_key,
+        application_id,
+        campaign,
+        destination_account,
+        None,
+    );
 
     let api_token = "API token".to_owned();
 
-    contract.runtime.add_expected_service_query(
-        application_id,
-        async_graphql::Request::new(format!(
-            "query {{ \
-                checkEligibility(address: \"{external_address}\", apiToken: \"{api_token}\") \
-            }}"
-        )),
-        async_graphql::Response::new(IndexMap::from_iter([(
-            async_graphql::Name::new("checkEligibility"),
-            async_graphql::Value::Boolean(true),
-        )])),
-    );
+    contract.runtime.set_chain_id(destination_account.chain_id);
 
     let claim = AirDropClaim {
-        signature,
+        campaign,
+        signature: signature.into(),
         destination: destination_account,
-        api_token,
+        api_token: api_token.clone(),
+        relayer_fee: None,
+        balance_proof: None,
+        allowlist_proof: None,
     };
 
-    let () = contract.execute_operation(claim).blocking_wait();
+    let () = contract
+        .execute_operation(Operation::Claim(claim))
+        .blocking_wait();
 
     let application_creator_chain_id = contract.runtime.application_creator_chain_id();
     let scheduled_messages = contract.runtime.created_send_message_requests();
@@ -60,31 +207,190 @@ fn accepts_new_claim() {
         authenticated: true,
         is_tracked: false,
         grant: Resources::default(),
-        message: ApprovedAirDrop {
-            id: external_address.into(),
-            amount: Amount::ONE,
+        message: Message::Claim(ClaimRequest {
+            id: AirDropId::new(campaign, external_address),
             destination: destination_account,
-        },
+            api_token,
+            relayer_fee: None,
+            proven_balance: None,
+            allowlist_proof: None,
+        }),
     };
 
     assert_eq!(*scheduled_messages, vec![expected_message]);
 }
 
-/// Tests if an accepted airdrop leads to a call to transfer the tokens to the claimer.
+/// Tests if an eligible claim leads to a call to transfer the tokens to the claimer.
 #[test]
-fn pays_accepted_airdrop() {
-    let (mut contract, _) = create_and_instantiate_contract();
-    let airdrop_id = AirDropId::from(Address::random());
-    let amount = Amount::from_tokens(11);
+fn pays_eligible_claim() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let airdrop_id = AirDropId::new(campaign, Address::random());
     let destination = create_dummy_destination(0);
+    let approved_at = Timestamp::from(1_000);
 
-    let airdrop = ApprovedAirDrop {
-        id: airdrop_id,
-        amount,
-        destination,
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    expect_eligibility_query(&mut contract, &airdrop_id, true);
+    contract.runtime.set_system_time(approved_at);
+
+    contract.runtime.set_call_application_handler(
+        move |is_authenticated, target_application, operation| {
+            assert!(is_authenticated);
+            assert_eq!(target_application, create_dummy_token_id());
+            assert_eq!(
+                operation,
+                bcs::to_bytes(&fungible::Operation::Transfer {
+                    owner: AccountOwner::Application(application_id.forget_abi()),
+                    amount: CLAIM_AMOUNT,
+                    target_account: destination,
+                })
+                .expect("Payment transfer should be serializable")
+            );
+
+            bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+        },
+    );
+
+    let () = contract
+        .execute_message(Message::Claim(create_claim_request(
+            airdrop_id,
+            destination,
+        )))
+        .blocking_wait();
+
+    assert_eq!(
+        read_budget(&contract, campaign),
+        Budget {
+            funded: Amount::from_tokens(10),
+            paid: CLAIM_AMOUNT,
+        }
+    );
+    assert_eq!(
+        read_claim_record(&contract, &airdrop_id),
+        Some(ClaimRecord {
+            destination,
+            amount: CLAIM_AMOUNT,
+            relayer_fee: None,
+            approved_at,
+            evidence: create_evidence(true),
+        })
+    );
+}
+
+/// Tests if the relayer fee of a claim is deducted from the payout and paid to the relayer that
+/// submitted the claim.
+#[test]
+fn pays_relayer_fee() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+    let destination = create_dummy_destination(0);
+    let relayer = create_dummy_destination(1);
+    let relayer_fee = Amount::from_attos(u128::from(CLAIM_AMOUNT) / 10);
+
+    let AccountOwner::User(relayer_owner) = relayer.owner else {
+        panic!("Dummy destination should be owned by a user");
     };
 
-    let application_id = contract.runtime.application_id();
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    expect_eligibility_query(&mut contract, &airdrop_id, true);
+
+    contract.runtime.set_authenticated_signer(relayer_owner);
+    contract
+        .runtime
+        .set_message_id(create_dummy_message_id(relayer.chain_id));
+
+    let transfers = Arc::new(Mutex::new(Vec::new()));
+    let recorded_transfers = transfers.clone();
+
+    contract.runtime.set_call_application_handler(
+        move |is_authenticated, target_application, operation| {
+            assert!(is_authenticated);
+            assert_eq!(target_application, create_dummy_token_id());
+
+            recorded_transfers
+                .lock()
+                .expect("Test should not panic while holding the lock")
+                .push(operation);
+
+            bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+        },
+    );
+
+    let mut request = create_claim_request(airdrop_id, destination);
+    request.relayer_fee = Some(relayer_fee);
+
+    let () = contract
+        .execute_message(Message::Claim(request))
+        .blocking_wait();
+
+    let owner = AccountOwner::Application(application_id.forget_abi());
+    let expected_transfers = [
+        fungible::Operation::Transfer {
+            owner,
+            amount: relayer_fee,
+            target_account: relayer,
+        },
+        fungible::Operation::Transfer {
+            owner,
+            amount: CLAIM_AMOUNT.saturating_sub(relayer_fee),
+            target_account: destination,
+        },
+    ]
+    .iter()
+    .map(|transfer| bcs::to_bytes(transfer).expect("Payment transfer should be serializable"))
+    .collect::<Vec<_>>();
+
+    assert_eq!(
+        *transfers
+            .lock()
+            .expect("Test should not panic while holding the lock"),
+        expected_transfers
+    );
+    assert_eq!(
+        read_budget(&contract, campaign),
+        Budget {
+            funded: Amount::from_tokens(10),
+            paid: CLAIM_AMOUNT,
+        }
+    );
+}
+
+/// Tests if a claim with a relayer fee larger than the airdropped amount is rejected.
+#[test]
+fn rejects_relayer_fee_above_payout() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+
+    let mut request = create_claim_request(airdrop_id, create_dummy_destination(0));
+    request.relayer_fee = Some(CLAIM_AMOUNT.saturating_add(Amount::ONE));
+
+    let () = contract
+        .execute_message(Message::Claim(request))
+        .blocking_wait();
+
+    assert_eq!(
+        read_rejection_reasons(&contract),
+        ["Relayer fee exceeds the airdropped amount"]
+    );
+    assert_eq!(read_claim_record(&contract, &airdrop_id), None);
+}
+
+/// Tests if a claim with a balance proven through Proof-of-SQL is paid without querying the
+/// Gateway.
+#[test]
+fn pays_claim_with_proven_balance() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let campaign_config = create_dummy_campaign(None);
+    let eligibility = campaign_config.eligibility.clone();
+    let campaign = add_campaign(&mut contract, campaign_config);
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+    let destination = create_dummy_destination(0);
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
 
     contract.runtime.set_call_application_handler(
         move |is_authenticated, target_application, operation| {
@@ -94,42 +400,278 @@ fn pays_accepted_airdrop() {
                 operation,
                 bcs::to_bytes(&fungible::Operation::Transfer {
                     owner: AccountOwner::Application(application_id.forget_abi()),
-                    amount,
+                    amount: CLAIM_AMOUNT,
                     target_account: destination,
                 })
-                .expect("`ApprovedAirDrop` message should be serializable")
+                .expect("Payment transfer should be serializable")
             );
 
             bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
         },
     );
 
-    let () = contract.execute_message(airdrop).blocking_wait();
+    let mut request = create_claim_request(airdrop_id, destination);
+    request.proven_balance = Some(ProvenBalance {
+        snapshot_block: eligibility.snapshot_block,
+        balance: MINIMUM_BALANCE,
+    });
+
+    let () = contract
+        .execute_message(Message::Claim(request))
+        .blocking_wait();
+
+    assert_eq!(read_budget(&contract, campaign).paid, CLAIM_AMOUNT);
+    assert_eq!(
+        read_claim_record(&contract, &airdrop_id).map(|record| record.evidence),
+        Some(EligibilityEvidence {
+            eligible: true,
+            balance: Some(MINIMUM_BALANCE),
+            block: eligibility.snapshot_block,
+            matched_rule: Some(eligibility.rule.to_string()),
+            amount: CLAIM_AMOUNT,
+        })
+    );
 }
 
-/// Tests if the same airdrop pays the claimer once.
+/// Tests if a balance proven at a block other than the campaign's snapshot block is rejected.
 #[test]
-#[should_panic(expected = "Airdrop has already been paid")]
-fn rejects_repeated_airdrop() {
+fn rejects_proven_balance_at_other_block() {
     let (mut contract, _) = create_and_instantiate_contract();
-    let airdrop_id = AirDropId::from(Address::random());
-    let amount = Amount::from_tokens(11);
-    let first_destination = create_dummy_destination(0);
-    let second_destination = create_dummy_destination(1);
+    let campaign_config = create_dummy_campaign(None);
+    let eligibility = campaign_config.eligibility.clone();
+    let campaign = add_campaign(&mut contract, campaign_config);
+    let airdrop_id = AirDropId::new(campaign, Address::random());
 
-    let first_claim = ApprovedAirDrop {
-        id: airdrop_id,
-        amount,
-        destination: first_destination,
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+
+    let mut request = create_claim_request(airdrop_id, create_dummy_destination(0));
+    request.proven_balance = Some(ProvenBalance {
+        snapshot_block: eligibility.snapshot_block + 1,
+        balance: MINIMUM_BALANCE,
+    });
+
+    let () = contract
+        .execute_message(Message::Claim(request))
+        .blocking_wait();
+
+    assert_eq!(
+        read_rejection_reasons(&contract),
+        ["Balance proof is not for the campaign's snapshot block"]
+    );
+    assert_eq!(read_claim_record(&contract, &airdrop_id), None);
+}
+
+/// Tests if a proven balance is rejected for a campaign with a holding period, since the proof
+/// is only for the balance at a single block.
+#[test]
+fn rejects_proven_balance_for_holding_period() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let mut campaign_config = create_dummy_campaign(None);
+    campaign_config.eligibility.rule = EligibilityRule::Criterion(Criterion::Balance {
+        minimum: MINIMUM_BALANCE,
+        holding: HoldingRule::Continuous { start_block: 50 },
+    });
+    let eligibility = campaign_config.eligibility.clone();
+    let campaign = add_campaign(&mut contract, campaign_config);
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+
+    let mut request = create_claim_request(airdrop_id, create_dummy_destination(0));
+    request.proven_balance = Some(ProvenBalance {
+        snapshot_block: eligibility.snapshot_block,
+        balance: MINIMUM_BALANCE,
+    });
+
+    let () = contract
+        .execute_message(Message::Claim(request))
+        .blocking_wait();
+
+    assert_eq!(
+        read_rejection_reasons(&contract),
+        ["Balance proofs are only accepted for campaigns that only check the snapshot balance"]
+    );
+    assert_eq!(read_claim_record(&contract, &airdrop_id), None);
+}
+
+/// Tests if a claimer in a campaign's allowlist is paid the amount allowlisted for their address.
+#[test]
+fn pays_allowlisted_claim() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let claimer = Address::random();
+    let allowlisted_amount = Amount::from_tokens(3);
+    let tree = AllowlistTree::new(&[
+        (Address::random(), Amount::from_tokens(1)),
+        (claimer, allowlisted_amount),
+        (Address::random(), Amount::from_tokens(2)),
+    ]);
+    let campaign = add_campaign(&mut contract, create_allowlist_campaign(&tree));
+    let airdrop_id = AirDropId::new(campaign, claimer);
+    let destination = create_dummy_destination(0);
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+
+    contract.runtime.set_call_application_handler(
+        move |is_authenticated, target_application, operation| {
+            assert!(is_authenticated);
+            assert_eq!(target_application, create_dummy_token_id());
+            assert_eq!(
+                operation,
+                bcs::to_bytes(&fungible::Operation::Transfer {
+                    owner: AccountOwner::Application(application_id.forget_abi()),
+                    amount: allowlisted_amount,
+                    target_account: destination,
+                })
+                .expect("Payment transfer should be serializable")
+            );
+
+            bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+        },
+    );
+
+    let (_, _, leaf_index) = tree.entries()[1];
+    let mut request = create_claim_request(airdrop_id, destination);
+    request.allowlist_proof = Some(tree.proof(leaf_index));
+
+    let () = contract
+        .execute_message(Message::Claim(request))
+        .blocking_wait();
+
+    assert_eq!(read_budget(&contract, campaign).paid, allowlisted_amount);
+    assert_eq!(
+        read_claim_record(&contract, &airdrop_id).map(|record| record.evidence.matched_rule),
+        Some(Some(format!("allowlisted({})", tree.root())))
+    );
+}
+
+/// Tests if an allowlist proof for another address is rejected.
+#[test]
+fn rejects_allowlist_proof_of_other_address() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let tree = AllowlistTree::new(&[
+        (Address::random(), Amount::from_tokens(1)),
+        (Address::random(), Amount::from_tokens(2)),
+    ]);
+    let campaign = add_campaign(&mut contract, create_allowlist_campaign(&tree));
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+
+    let (_, _, leaf_index) = tree.entries()[0];
+    let mut request = create_claim_request(airdrop_id, create_dummy_destination(0));
+    request.allowlist_proof = Some(tree.proof(leaf_index));
+
+    let () = contract
+        .execute_message(Message::Claim(request))
+        .blocking_wait();
+
+    assert_eq!(
+        read_rejection_reasons(&contract),
+        ["Failed to verify allowlist proof"]
+    );
+    assert_eq!(read_claim_record(&contract, &airdrop_id), None);
+}
+
+/// Tests if a campaign with allowlisted amounts but without an allowlist is rejected.
+#[test]
+#[should_panic(
+    expected = "Allowlisted amounts require a single allowlist eligibility rule, and vice versa"
+)]
+fn rejects_allowlisted_amounts_without_allowlist() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let campaign = Campaign {
+        amount: AmountPolicy::Allowlisted,
+        ..create_dummy_campaign(None)
     };
 
-    let second_claim = ApprovedAirDrop {
-        id: airdrop_id,
-        amount: Amount::ONE,
-        destination: second_destination,
+    contract.runtime.set_authenticated_signer(admin());
+
+    let () = contract
+        .execute_operation(Operation::CreateCampaign(campaign))
+        .blocking_wait();
+}
+
+/// Tests if claims with balance proofs are rejected when the application has no Proof-of-SQL
+/// commitments to verify them against.
+#[test]
+#[should_panic(expected = "Balance proofs are not enabled for this application")]
+fn rejects_balance_proof_without_commitments() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let signing_key = SigningKey::random(&mut OsRng);
+    let destination = create_dummy_destination(0);
+    let campaign = CampaignId(0);
+
+    contract.runtime.set_chain_id(destination.chain_id);
+
+    let claim = AirDropClaim {
+        campaign,
+        signature: sign_claim(&signing_key, application_id, campaign, destination, None).into(),
+        destination,
+        api_token: String::new(),
+        relayer_fee: None,
+        balance_proof: Some(BalanceProof {
+            snapshot_block: 100,
+            verifiable_result: vec![],
+        }),
+        allowlist_proof: None,
     };
 
-    let application_id = contract.runtime.application_id();
+    let () = contract
+        .execute_operation(Operation::Claim(claim))
+        .blocking_wait();
+}
+
+/// Tests if the proven balance query reads the tables of the network configured in the
+/// application's parameters.
+#[test]
+fn balance_proof_query_uses_configured_network() {
+    let address = Address::random();
+    let lowercase_address = address.to_string().to_lowercase();
+
+    assert_eq!(
+        balance_query(&Network::Polygon, address, 100),
+        format!(
+            "SELECT BALANCE, BLOCK_NUMBER FROM POLYGON.NATIVE_WALLETS \
+            WHERE WALLET_ADDRESS = '{lowercase_address}' AND BLOCK_NUMBER <= 100"
+        )
+    );
+}
+
+/// Tests if a claim for an ineligible address is rejected.
+#[test]
+fn rejects_ineligible_claim() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    expect_eligibility_query(&mut contract, &airdrop_id, false);
+
+    let () = contract
+        .execute_message(Message::Claim(create_claim_request(
+            airdrop_id,
+            create_dummy_destination(0),
+        )))
+        .blocking_wait();
+
+    assert_eq!(
+        read_rejection_reasons(&contract),
+        ["Claimer is not eligible for the airdrop"]
+    );
+    assert_eq!(read_claim_record(&contract, &airdrop_id), None);
+}
+
+/// Tests if the same airdrop pays the claimer once.
+#[test]
+fn rejects_repeated_airdrop() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+    let first_destination = create_dummy_destination(0);
+    let second_destination = create_dummy_destination(1);
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    expect_eligibility_query(&mut contract, &airdrop_id, true);
 
     contract.runtime.set_call_application_handler(
         move |is_authenticated, target_application, operation| {
@@ -139,54 +681,1320 @@ fn rejects_repeated_airdrop() {
                 operation,
                 bcs::to_bytes(&fungible::Operation::Transfer {
                     owner: AccountOwner::Application(application_id.forget_abi()),
-                    amount,
+                    amount: CLAIM_AMOUNT,
                     target_account: first_destination,
                 })
-                .expect("`ApprovedAirDrop` message should be serializable")
+                .expect("Payment transfer should be serializable")
             );
 
             bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
         },
     );
 
-    let () = contract.execute_message(first_claim).blocking_wait();
-    let () = contract.execute_message(second_claim).blocking_wait();
+    let () = contract
+        .execute_message(Message::Claim(create_claim_request(
+            airdrop_id,
+            first_destination,
+        )))
+        .blocking_wait();
+    let () = contract
+        .execute_message(Message::Claim(create_claim_request(
+            airdrop_id,
+            second_destination,
+        )))
+        .blocking_wait();
+
+    assert_eq!(
+        read_rejection_reasons(&contract),
+        ["Airdrop has already been paid"]
+    );
+    assert_eq!(
+        read_claim_record(&contract, &airdrop_id).map(|record| record.destination),
+        Some(first_destination)
+    );
+    assert_eq!(read_budget(&contract, campaign).paid, CLAIM_AMOUNT);
 }
 
-/// Creates an [`ApplicationContract`] instance and calls `instantiate` on it.
-///
-/// Returns the [`ApplicationContract`] instance along with a dummy [`ApplicationId`] that was
-/// assigned to it.
-fn create_and_instantiate_contract() -> (ApplicationContract, ApplicationId<ApplicationAbi>) {
-    let application_id = create_dummy_application_id("zk-airdrop", 1);
+/// Tests if the claims of a batch are forwarded to the creator chain in a single message.
+#[test]
+fn forwards_claim_batch_in_single_message() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let campaign = CampaignId(3);
+    let submitting_chain = create_dummy_destination(0).chain_id;
 
-    let runtime = ContractRuntime::new()
-        .with_application_parameters(Parameters {
-            token_id: create_dummy_token_id(),
-            snapshot_block: 100,
-            minimum_balance: U256::from(1),
-        })
-        .with_application_id(application_id)
-        .with_application_creator_chain_id(ChainId(CryptoHash::test_hash("creator chain")));
+    contract.runtime.set_chain_id(submitting_chain);
 
-    let mut contract = ApplicationContract {
-        state: Application::load(runtime.root_view_storage_context())
-            .blocking_wait()
-            .expect("Failed to read from mock key value store"),
-        runtime,
-    };
+    let (claims, expected_requests): (Vec<_>, Vec<_>) = (0..3)
+        .map(|index| {
+            let signing_key = SigningKey::random(&mut OsRng);
+            let destination = create_dummy_destination(index);
+            let signature = sign_claim(&signing_key, application_id, campaign, destination, None);
 
-    contract.instantiate(()).blocking_wait();
+            let claim = AirDropClaim {
+                campaign,
+                signature: signature.into(),
+                destination,
+                api_token: API_TOKEN.to_owned(),
+                relayer_fee: None,
+                balance_proof: None,
+                allowlist_proof: None,
+            };
+            let request = ClaimRequest {
+                id: AirDropId::new(campaign, Address::from_private_key(&signing_key)),
+                destination,
+                api_token: API_TOKEN.to_owned(),
+                relayer_fee: None,
+                proven_balance: None,
+                allowlist_proof: None,
+            };
 
-    (contract, application_id)
-}
+            (claim, request)
+        })
+        .unzip();
 
-/// Creates a dummy [`Account`] to use as a test destination for the airdropped tokens.
-fn create_dummy_destination(index: usize) -> Account {
-    Account {
-        chain_id: ChainId(CryptoHash::test_hash(format!("destination chain {index}"))),
-        owner: AccountOwner::User(Owner(CryptoHash::test_hash(format!(
-            "destination owner {index}"
+    let () = contract
+        .execute_operation(Operation::ClaimBatch(claims))
+        .blocking_wait();
+
+    let application_creator_chain_id = contract.runtime.application_creator_chain_id();
+    let scheduled_messages = contract.runtime.created_send_message_requests();
+
+    let expected_message = SendMessageRequest {
+        destination: Destination::Recipient(application_creator_chain_id),
+        authenticated: true,
+        is_tracked: false,
+        grant: Resources::default(),
+        message: Message::ClaimBatch(expected_requests),
+    };
+
+    assert_eq!(*scheduled_messages, vec![expected_message]);
+}
+
+/// Tests if the rejected claims of a batch are skipped without preventing the approved claims
+/// from being paid.
+#[test]
+fn pays_approved_claims_in_batch() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let eligible_airdrop = AirDropId::new(campaign, Address::random());
+    let ineligible_airdrop = AirDropId::new(campaign, Address::random());
+    let destination = create_dummy_destination(0);
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    expect_eligibility_query(&mut contract, &eligible_airdrop, true);
+    expect_eligibility_query(&mut contract, &ineligible_airdrop, false);
+
+    contract.runtime.set_call_application_handler(
+        move |is_authenticated, target_application, operation| {
+            assert!(is_authenticated);
+            assert_eq!(target_application, create_dummy_token_id());
+            assert_eq!(
+                operation,
+                bcs::to_bytes(&fungible::Operation::Transfer {
+                    owner: AccountOwner::Application(application_id.forget_abi()),
+                    amount: CLAIM_AMOUNT,
+                    target_account: destination,
+                })
+                .expect("Payment transfer should be serializable")
+            );
+
+            bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+        },
+    );
+
+    let () = contract
+        .execute_message(Message::ClaimBatch(vec![
+            create_claim_request(eligible_airdrop, destination),
+            create_claim_request(ineligible_airdrop, create_dummy_destination(1)),
+            create_claim_request(eligible_airdrop, create_dummy_destination(2)),
+        ]))
+        .blocking_wait();
+
+    let is_handled = |airdrop: &AirDropId| {
+        contract
+            .state
+            .handled_airdrops
+            .contains_key(airdrop)
+            .blocking_wait()
+            .expect("Failed to read handled claims")
+    };
+
+    assert!(is_handled(&eligible_airdrop));
+    assert!(!is_handled(&ineligible_airdrop));
+    assert_eq!(contract.state.rejected_claims.count(), 2);
+    assert_eq!(
+        read_budget(&contract, campaign),
+        Budget {
+            funded: Amount::from_tokens(10),
+            paid: CLAIM_AMOUNT,
+        }
+    );
+}
+
+/// Tests if a claim from a denylisted address is recorded as rejected without paying it.
+#[test]
+fn records_denied_claim() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let address = Address::random();
+    let airdrop_id = AirDropId::new(campaign, address);
+    let destination = create_dummy_destination(0);
+    let rejected_at = Timestamp::from(1_000);
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+
+    contract.runtime.set_authenticated_signer(admin());
+    contract.runtime.set_system_time(rejected_at);
+
+    let () = contract
+        .execute_operation(Operation::UpdateDenylist {
+            add: vec![DenylistEntry::ExternalAddress(address.into())],
+            remove: vec![],
+        })
+        .blocking_wait();
+
+    let () = contract
+        .execute_message(Message::Claim(create_claim_request(
+            airdrop_id,
+            destination,
+        )))
+        .blocking_wait();
+
+    assert_eq!(
+        read_rejected_claims(&contract),
+        vec![RejectedClaim {
+            id: airdrop_id,
+            destination,
+            reason: "Claimer address is in the denylist".to_owned(),
+            timestamp: rejected_at,
+        }]
+    );
+    assert_eq!(read_budget(&contract, campaign).paid, Amount::ZERO);
+}
+
+/// Tests if only the admin can update the denylist.
+#[test]
+#[should_panic(expected = "Only the admin can update the denylist")]
+fn rejects_denylist_update_from_non_admin() {
+    let (mut contract, _) = create_and_instantiate_contract();
+
+    contract
+        .runtime
+        .set_authenticated_signer(Owner(CryptoHash::test_hash("impostor")));
+
+    let () = contract
+        .execute_operation(Operation::UpdateDenylist {
+            add: vec![DenylistEntry::Chain(create_dummy_destination(0).chain_id)],
+            remove: vec![],
+        })
+        .blocking_wait();
+}
+
+/// Tests if the same address can claim airdrops from different campaigns.
+#[test]
+fn pays_same_address_in_different_campaigns() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let first_campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let second_campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let address = Address::random();
+
+    contract.runtime.set_call_application_handler(|_, _, _| {
+        bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+    });
+
+    for campaign in [first_campaign, second_campaign] {
+        let airdrop_id = AirDropId::new(campaign, address);
+
+        fund_campaign(&mut contract, campaign, CLAIM_AMOUNT);
+        expect_eligibility_query(&mut contract, &airdrop_id, true);
+
+        let () = contract
+            .execute_message(Message::Claim(create_claim_request(
+                airdrop_id,
+                create_dummy_destination(0),
+            )))
+            .blocking_wait();
+
+        assert_eq!(
+            read_budget(&contract, campaign).remaining_amount(),
+            Amount::ZERO
+        );
+    }
+}
+
+/// Tests if a claim that reaches the creator chain outside of its campaign's window is rejected.
+#[test]
+fn rejects_claim_outside_window() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let campaign = add_campaign(
+        &mut contract,
+        Campaign {
+            window: ClaimWindow {
+                start: Timestamp::from(0),
+                end: Timestamp::from(500),
+            },
+            ..create_dummy_campaign(None)
+        },
+    );
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    contract.runtime.set_system_time(Timestamp::from(1_000));
+
+    let () = contract
+        .execute_message(Message::Claim(create_claim_request(
+            airdrop_id,
+            create_dummy_destination(0),
+        )))
+        .blocking_wait();
+
+    assert_eq!(
+        read_rejection_reasons(&contract),
+        ["Campaign is not accepting claims"]
+    );
+    assert_eq!(read_claim_record(&contract, &airdrop_id), None);
+}
+
+/// Tests if a claim is rejected when its campaign's budget is not enough to pay it.
+#[test]
+fn rejects_claim_exceeding_budget() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+
+    expect_eligibility_query(&mut contract, &airdrop_id, true);
+
+    let () = contract
+        .execute_message(Message::Claim(create_claim_request(
+            airdrop_id,
+            create_dummy_destination(0),
+        )))
+        .blocking_wait();
+
+    assert_eq!(
+        read_rejection_reasons(&contract),
+        ["Campaign budget is exhausted"]
+    );
+    assert_eq!(read_claim_record(&contract, &airdrop_id), None);
+}
+
+/// Tests if funding a campaign on the creator chain transfers the tokens and records the
+/// deposit.
+#[test]
+fn records_funding_on_creator_chain() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let creator_chain = contract.runtime.application_creator_chain_id();
+    let funder = Owner(CryptoHash::test_hash("funder"));
+    let amount = Amount::from_tokens(7);
+    let timestamp = Timestamp::from(1_000);
+
+    contract.runtime.set_authenticated_signer(funder);
+    contract.runtime.set_system_time(timestamp);
+
+    contract.runtime.set_call_application_handler(
+        move |is_authenticated, target_application, operation| {
+            assert!(is_authenticated);
+            assert_eq!(target_application, create_dummy_token_id());
+            assert_eq!(
+                operation,
+                bcs::to_bytes(&fungible::Operation::Transfer {
+                    owner: AccountOwner::User(funder),
+                    amount,
+                    target_account: Account {
+                        chain_id: creator_chain,
+                        owner: AccountOwner::Application(application_id.forget_abi()),
+                    },
+                })
+                .expect("Funding transfer should be serializable")
+            );
+
+            bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+        },
+    );
+
+    let () = contract
+        .execute_operation(Operation::Fund { campaign, amount })
+        .blocking_wait();
+    let () = contract
+        .execute_operation(Operation::Fund { campaign, amount })
+        .blocking_wait();
+
+    let expected_deposit = Deposit {
+        campaign,
+        funder: AccountOwner::User(funder),
+        amount,
+        timestamp,
+    };
+
+    assert!(contract.runtime.created_send_message_requests().is_empty());
+    assert_eq!(
+        read_budget(&contract, campaign),
+        Budget {
+            funded: amount.saturating_add(amount),
+            paid: Amount::ZERO,
+        }
+    );
+    assert_eq!(
+        contract
+            .state
+            .funders
+            .get(&AccountOwner::User(funder))
+            .blocking_wait()
+            .expect("Failed to read funder's deposits"),
+        Some(amount.saturating_add(amount))
+    );
+    assert_eq!(
+        contract
+            .state
+            .deposits
+            .read(0..2)
+            .blocking_wait()
+            .expect("Failed to read deposit history"),
+        vec![expected_deposit.clone(), expected_deposit]
+    );
+}
+
+/// Tests if funding is rejected outside of the creator chain, where the campaign can't be
+/// checked before the tokens are transferred.
+#[test]
+#[should_panic(expected = "Campaigns can only be funded on the application's creator chain")]
+fn rejects_funding_from_other_chain() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let funder = create_dummy_destination(0);
+
+    contract.runtime.set_chain_id(funder.chain_id);
+    contract
+        .runtime
+        .set_authenticated_signer(Owner(CryptoHash::test_hash("funder")));
+
+    let () = contract
+        .execute_operation(Operation::Fund {
+            campaign: CampaignId(0),
+            amount: Amount::ONE,
+        })
+        .blocking_wait();
+}
+
+/// Tests if funding an unknown campaign is rejected before any tokens are transferred.
+#[test]
+#[should_panic(expected = "Unknown campaign")]
+fn rejects_funding_unknown_campaign() {
+    let (mut contract, _) = create_and_instantiate_contract();
+
+    contract
+        .runtime
+        .set_authenticated_signer(Owner(CryptoHash::test_hash("funder")));
+    contract.runtime.set_call_application_handler(|_, _, _| {
+        panic!("No tokens should be transferred to fund an unknown campaign")
+    });
+
+    let () = contract
+        .execute_operation(Operation::Fund {
+            campaign: CampaignId(0),
+            amount: Amount::ONE,
+        })
+        .blocking_wait();
+}
+
+/// Tests if an approved claim with a vesting schedule creates a vesting position instead of
+/// transferring the tokens.
+#[test]
+fn starts_vesting_approved_claim() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let campaign = add_campaign(
+        &mut contract,
+        create_dummy_campaign(Some(create_vesting_schedule())),
+    );
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+    let destination = create_dummy_destination(0);
+    let start = Timestamp::from(1_000);
+
+    contract.runtime.set_system_time(start);
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    expect_eligibility_query(&mut contract, &airdrop_id, true);
+
+    let () = contract
+        .execute_message(Message::Claim(create_claim_request(
+            airdrop_id,
+            destination,
+        )))
+        .blocking_wait();
+
+    let position = contract
+        .state
+        .vesting_positions
+        .get(&airdrop_id)
+        .blocking_wait()
+        .expect("Failed to read vesting position");
+
+    assert_eq!(
+        position,
+        Some(VestingPosition {
+            destination,
+            total: CLAIM_AMOUNT,
+            released: Amount::ZERO,
+            start,
+        })
+    );
+}
+
+/// Tests if a release request from the destination chain pays the tokens that have vested so
+/// far.
+#[test]
+fn releases_vested_tokens() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let campaign = add_campaign(
+        &mut contract,
+        create_dummy_campaign(Some(create_vesting_schedule())),
+    );
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+    let destination = create_dummy_destination(0);
+    let start = Timestamp::from(1_000);
+    let position = VestingPosition {
+        destination,
+        total: Amount::from_tokens(100),
+        released: Amount::from_tokens(10),
+        start,
+    };
+
+    contract
+        .state
+        .vesting_positions
+        .insert(&airdrop_id, position.clone())
+        .expect("Failed to write vesting position");
+
+    contract
+        .runtime
+        .set_system_time(start.saturating_add(TimeDelta::from_secs(55)));
+    contract
+        .runtime
+        .set_message_id(create_dummy_message_id(destination.chain_id));
+
+    let releasable = Amount::from_tokens(40);
+
+    contract.runtime.set_call_application_handler(
+        move |is_authenticated, target_application, operation| {
+            assert!(is_authenticated);
+            assert_eq!(target_application, create_dummy_token_id());
+            assert_eq!(
+                operation,
+                bcs::to_bytes(&fungible::Operation::Transfer {
+                    owner: AccountOwner::Application(application_id.forget_abi()),
+                    amount: releasable,
+                    target_account: destination,
+                })
+                .expect("Release transfer should be serializable")
+            );
+
+            bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+        },
+    );
+
+    let () = contract
+        .execute_message(Message::Release { id: airdrop_id })
+        .blocking_wait();
+
+    let updated_position = contract
+        .state
+        .vesting_positions
+        .get(&airdrop_id)
+        .blocking_wait()
+        .expect("Failed to read vesting position");
+
+    assert_eq!(
+        updated_position,
+        Some(VestingPosition {
+            released: Amount::from_tokens(50),
+            ..position
+        })
+    );
+}
+
+/// Tests if a chain can't request the release of tokens vested for another chain.
+#[test]
+#[should_panic(expected = "Vested tokens can only be released by their destination chain")]
+fn rejects_release_from_other_chain() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let campaign = add_campaign(
+        &mut contract,
+        create_dummy_campaign(Some(create_vesting_schedule())),
+    );
+    let airdrop_id = AirDropId::new(campaign, Address::random());
+    let destination = create_dummy_destination(0);
+    let attacker_chain = create_dummy_destination(1).chain_id;
+    let start = Timestamp::from(1_000);
+
+    contract
+        .state
+        .vesting_positions
+        .insert(
+            &airdrop_id,
+            VestingPosition {
+                destination,
+                total: Amount::from_tokens(100),
+                released: Amount::ZERO,
+                start,
+            },
+        )
+        .expect("Failed to write vesting position");
+
+    contract
+        .runtime
+        .set_system_time(start.saturating_add(TimeDelta::from_secs(200)));
+    contract
+        .runtime
+        .set_message_id(create_dummy_message_id(attacker_chain));
+
+    let () = contract
+        .execute_message(Message::Release { id: airdrop_id })
+        .blocking_wait();
+}
+
+/// Tests if a Linera claim is forwarded to the creator chain with the claim operation's
+/// authenticated signer and the chain it was submitted from.
+#[test]
+fn forwards_linera_claim() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let claimer = Owner(CryptoHash::test_hash("Linera claimer"));
+    let destination = create_dummy_destination(0);
+    let campaign = CampaignId(3);
+
+    contract.runtime.set_chain_id(destination.chain_id);
+    contract.runtime.set_authenticated_signer(claimer);
+
+    let () = contract
+        .execute_operation(Operation::LineraClaim {
+            campaign,
+            destination,
+        })
+        .blocking_wait();
+
+    let expected_message = SendMessageRequest {
+        destination: Destination::Recipient(contract.runtime.application_creator_chain_id()),
+        authenticated: true,
+        is_tracked: false,
+        grant: Resources::default(),
+        message: Message::LineraClaim(LineraClaimRequest {
+            campaign,
+            owner: Some(claimer),
+            chain_id: destination.chain_id,
+            destination,
+        }),
+    };
+
+    assert_eq!(
+        *contract.runtime.created_send_message_requests(),
+        vec![expected_message]
+    );
+}
+
+/// Tests if a Linera claim by one of a campaign's member owners is paid.
+#[test]
+fn pays_linera_member() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let member = Owner(CryptoHash::test_hash("Linera member"));
+    let campaign = add_campaign(
+        &mut contract,
+        create_linera_campaign([LineraIdentity::Owner(member)]),
+    );
+    let destination = create_dummy_destination(0);
+    let approved_at = Timestamp::from(1_000);
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    contract.runtime.set_system_time(approved_at);
+
+    contract.runtime.set_call_application_handler(
+        move |is_authenticated, target_application, operation| {
+            assert!(is_authenticated);
+            assert_eq!(target_application, create_dummy_token_id());
+            assert_eq!(
+                operation,
+                bcs::to_bytes(&fungible::Operation::Transfer {
+                    owner: AccountOwner::Application(application_id.forget_abi()),
+                    amount: CLAIM_AMOUNT,
+                    target_account: destination,
+                })
+                .expect("Payment transfer should be serializable")
+            );
+
+            bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+        },
+    );
+
+    let () = contract
+        .execute_message(Message::LineraClaim(create_linera_claim_request(
+            campaign,
+            Some(member),
+            destination,
+        )))
+        .blocking_wait();
+
+    let airdrop_id = LineraAirDropId {
+        campaign,
+        claimer: LineraIdentity::Owner(member),
+    };
+
+    assert_eq!(read_budget(&contract, campaign).paid, CLAIM_AMOUNT);
+    assert_eq!(
+        read_linera_claim_record(&contract, &airdrop_id),
+        Some(ClaimRecord {
+            destination,
+            amount: CLAIM_AMOUNT,
+            relayer_fee: None,
+            approved_at,
+            evidence: EligibilityEvidence {
+                eligible: true,
+                balance: None,
+                block: 100,
+                matched_rule: Some("linera_member(1 members)".to_owned()),
+                amount: CLAIM_AMOUNT,
+            },
+        })
+    );
+}
+
+/// Tests if a Linera claim submitted from a member chain is paid to that chain's identity when
+/// its signer isn't a member.
+#[test]
+fn pays_linera_claim_from_member_chain() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let destination = create_dummy_destination(0);
+    let campaign = add_campaign(
+        &mut contract,
+        create_linera_campaign([LineraIdentity::Chain(destination.chain_id)]),
+    );
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    contract.runtime.set_call_application_handler(|_, _, _| {
+        bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+    });
+
+    let signer = Owner(CryptoHash::test_hash("chain owner"));
+    let () = contract
+        .execute_message(Message::LineraClaim(create_linera_claim_request(
+            campaign,
+            Some(signer),
+            destination,
+        )))
+        .blocking_wait();
+
+    let chain_airdrop = LineraAirDropId {
+        campaign,
+        claimer: LineraIdentity::Chain(destination.chain_id),
+    };
+    let owner_airdrop = LineraAirDropId {
+        campaign,
+        claimer: LineraIdentity::Owner(signer),
+    };
+
+    assert!(read_linera_claim_record(&contract, &chain_airdrop).is_some());
+    assert!(read_linera_claim_record(&contract, &owner_airdrop).is_none());
+}
+
+/// Tests if a Linera claim by an identity that isn't one of the campaign's members is rejected.
+#[test]
+#[should_panic(expected = "Claimer is not eligible for the airdrop")]
+fn rejects_linera_claim_from_non_member() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let member = Owner(CryptoHash::test_hash("Linera member"));
+    let campaign = add_campaign(
+        &mut contract,
+        create_linera_campaign([LineraIdentity::Owner(member)]),
+    );
+    let outsider = Owner(CryptoHash::test_hash("outsider"));
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+
+    let () = contract
+        .execute_message(Message::LineraClaim(create_linera_claim_request(
+            campaign,
+            Some(outsider),
+            create_dummy_destination(0),
+        )))
+        .blocking_wait();
+}
+
+/// Tests if a Linera member is paid only once per campaign.
+#[test]
+#[should_panic(expected = "Airdrop has already been paid")]
+fn rejects_repeated_linera_claim() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let member = Owner(CryptoHash::test_hash("Linera member"));
+    let campaign = add_campaign(
+        &mut contract,
+        create_linera_campaign([LineraIdentity::Owner(member)]),
+    );
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    contract.runtime.set_call_application_handler(|_, _, _| {
+        bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+    });
+
+    for destination_index in 0..2 {
+        let () = contract
+            .execute_message(Message::LineraClaim(create_linera_claim_request(
+                campaign,
+                Some(member),
+                create_dummy_destination(destination_index),
+            )))
+            .blocking_wait();
+    }
+}
+
+/// Tests if a Linera claim is rejected by a campaign that checks Ethereum addresses.
+#[test]
+#[should_panic(expected = "Linera claims are only accepted for campaigns with Linera members")]
+fn rejects_linera_claim_for_ethereum_campaign() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let campaign = add_campaign(&mut contract, create_dummy_campaign(None));
+    let claimer = Owner(CryptoHash::test_hash("Linera claimer"));
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+
+    let () = contract
+        .execute_message(Message::LineraClaim(create_linera_claim_request(
+            campaign,
+            Some(claimer),
+            create_dummy_destination(0),
+        )))
+        .blocking_wait();
+}
+
+/// Tests if a campaign that combines criteria on Linera identities with criteria on Ethereum
+/// addresses is rejected.
+#[test]
+#[should_panic(expected = "Criteria on Linera identities can't be combined with Ethereum criteria")]
+fn rejects_campaign_mixing_linera_and_ethereum_criteria() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let mut campaign = create_linera_campaign([LineraIdentity::Owner(admin())]);
+    campaign.eligibility.rule = EligibilityRule::Any(vec![
+        campaign.eligibility.rule,
+        EligibilityRule::minimum_balance(MINIMUM_BALANCE),
+    ]);
+
+    contract.runtime.set_authenticated_signer(admin());
+
+    let () = contract
+        .execute_operation(Operation::CreateCampaign(campaign))
+        .blocking_wait();
+}
+
+/// Tests if a Linera claim by a user holding the minimum balance of a Linera token on the creator
+/// chain is paid, reading the balance from the token application when the claim is handled.
+#[test]
+fn pays_linera_token_holder() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let holder = Owner(CryptoHash::test_hash("token holder"));
+    let campaign = add_campaign(
+        &mut contract,
+        create_linera_token_campaign(Amount::from_tokens(5)),
+    );
+    let destination = create_dummy_destination(0);
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    expect_linera_token_balance(
+        &mut contract,
+        application_id,
+        holder,
+        Amount::from_tokens(5),
+    );
+
+    let () = contract
+        .execute_message(Message::LineraClaim(create_linera_claim_request(
+            campaign,
+            Some(holder),
+            destination,
+        )))
+        .blocking_wait();
+
+    let airdrop_id = LineraAirDropId {
+        campaign,
+        claimer: LineraIdentity::Owner(holder),
+    };
+
+    assert_eq!(read_budget(&contract, campaign).paid, CLAIM_AMOUNT);
+    assert!(read_linera_claim_record(&contract, &airdrop_id).is_some());
+}
+
+/// Tests if a Linera claim by a user without the minimum balance of a Linera token is rejected.
+#[test]
+#[should_panic(expected = "Claimer is not eligible for the airdrop")]
+fn rejects_linera_claim_below_token_balance() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let holder = Owner(CryptoHash::test_hash("token holder"));
+    let campaign = add_campaign(
+        &mut contract,
+        create_linera_token_campaign(Amount::from_tokens(5)),
+    );
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+    expect_linera_token_balance(
+        &mut contract,
+        application_id,
+        holder,
+        Amount::from_tokens(4),
+    );
+
+    let () = contract
+        .execute_message(Message::LineraClaim(create_linera_claim_request(
+            campaign,
+            Some(holder),
+            create_dummy_destination(0),
+        )))
+        .blocking_wait();
+}
+
+/// Tests if a Linera claim is paid to a chain that a membership application reports as a
+/// member, after asking it about the claim's signer.
+#[test]
+fn pays_linera_application_member() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let staking_application = create_dummy_application_id::<MembershipAbi>("staking", 0);
+    let signer = Owner(CryptoHash::test_hash("chain owner"));
+    let destination = create_dummy_destination(0);
+    let staker = LineraIdentity::Chain(destination.chain_id);
+    let mut campaign = create_dummy_campaign(None);
+    campaign.eligibility.rule = EligibilityRule::Criterion(Criterion::LineraApplicationMember {
+        application: staking_application,
+    });
+    let campaign = add_campaign(&mut contract, campaign);
+    let queried_identities = Arc::new(Mutex::new(Vec::new()));
+
+    fund_campaign(&mut contract, campaign, Amount::from_tokens(10));
+
+    contract.runtime.set_call_application_handler({
+        let queried_identities = queried_identities.clone();
+        move |is_authenticated, target_application, operation| {
+            if target_application == create_dummy_token_id() {
+                return bcs::to_bytes(&FungibleResponse::Ok)
+                    .expect("Unit type should be serializable");
+            }
+
+            assert!(!is_authenticated);
+            assert_eq!(target_application, staking_application.forget_abi());
+
+            let MembershipOperation::IsMember { identity } =
+                bcs::from_bytes(&operation).expect("Membership query should be deserializable");
+            queried_identities
+                .lock()
+                .expect("Test should abort on panic, so mutex should never be poisoned")
+                .push(identity);
+
+            bcs::to_bytes(&(identity == staker)).expect("Boolean should be serializable")
+        }
+    });
+
+    let () = contract
+        .execute_message(Message::LineraClaim(create_linera_claim_request(
+            campaign,
+            Some(signer),
+            destination,
+        )))
+        .blocking_wait();
+
+    let airdrop_id = LineraAirDropId {
+        campaign,
+        claimer: staker,
+    };
+
+    assert_eq!(
+        *queried_identities
+            .lock()
+            .expect("Test should abort on panic, so mutex should never be poisoned"),
+        vec![LineraIdentity::Owner(signer), staker]
+    );
+    assert!(read_linera_claim_record(&contract, &airdrop_id).is_some());
+}
+
+/// Tests if a campaign with a Linera token balance criterion is rejected if the token
+/// application isn't a fungible token.
+#[test]
+#[should_panic(expected = "Token application is not a fungible token")]
+fn rejects_campaign_with_invalid_linera_token() {
+    let (mut contract, _) = create_and_instantiate_contract();
+
+    contract.runtime.set_authenticated_signer(admin());
+    contract.runtime.set_call_application_handler(|_, _, _| {
+        bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+    });
+
+    let () = contract
+        .execute_operation(Operation::CreateCampaign(create_linera_token_campaign(
+            Amount::ONE,
+        )))
+        .blocking_wait();
+}
+
+/// The amount paid for each claim in the test campaigns.
+const CLAIM_AMOUNT: Amount = Amount::ONE;
+
+/// The minimum balance required by the test campaigns.
+const MINIMUM_BALANCE: U256 = U256::from_limbs([1, 0, 0, 0]);
+
+/// Creates the [`VestingSchedule`] used in the tests.
+fn create_vesting_schedule() -> VestingSchedule {
+    VestingSchedule {
+        cliff: TimeDelta::from_secs(10),
+        duration: TimeDelta::from_secs(100),
+        release_interval: TimeDelta::from_secs(10),
+    }
+}
+
+/// Returns the [`Owner`] used as the admin of the application in the tests.
+fn admin() -> Owner {
+    Owner(CryptoHash::test_hash("admin"))
+}
+
+/// Creates an [`ApplicationContract`] instance and calls `instantiate` on it, with the
+/// [`Parameters`] created by [`create_parameters`].
+///
+/// The contract runs on the application's creator chain.
+///
+/// Returns the [`ApplicationContract`] instance along with a dummy [`ApplicationId`] that was
+/// assigned to it.
+fn create_and_instantiate_contract() -> (ApplicationContract, ApplicationId<ApplicationAbi>) {
+    create_and_instantiate_contract_with(create_parameters())
+}
+
+/// Creates an [`ApplicationContract`] instance with custom [`Parameters`] and calls
+/// `instantiate` on it.
+///
+/// The token application answers the balance query made when instantiating the contract.
+fn create_and_instantiate_contract_with(
+    parameters: Parameters,
+) -> (ApplicationContract, ApplicationId<ApplicationAbi>) {
+    let application_id = create_dummy_application_id("zk-airdrop", 1);
+    let creator_chain = ChainId(CryptoHash::test_hash("creator chain"));
+
+    let mut runtime = ContractRuntime::new()
+        .with_application_parameters(parameters)
+        .with_application_id(application_id)
+        .with_application_creator_chain_id(creator_chain)
+        .with_chain_id(creator_chain)
+        .with_system_time(Timestamp::from(0));
+
+    runtime.set_call_application_handler(|_, target_application, operation| {
+        assert_eq!(target_application, create_dummy_token_id());
+        assert_eq!(
+            operation,
+            bcs::to_bytes(&fungible::Operation::Balance {
+                owner: AccountOwner::Application(application_id.forget_abi()),
+            })
+            .expect("Balance query should be serializable")
+        );
+
+        bcs::to_bytes(&FungibleResponse::Balance(Amount::ZERO))
+            .expect("Balance response should be serializable")
+    });
+
+    let mut contract = ApplicationContract {
+        state: Application::load(runtime.root_view_storage_context())
+            .blocking_wait()
+            .expect("Failed to read from mock key value store"),
+        runtime,
+    };
+
+    contract.instantiate(()).blocking_wait();
+
+    (contract, application_id)
+}
+
+/// Creates the [`Parameters`] used by most tests.
+fn create_parameters() -> Parameters {
+    Parameters {
+        token_id: create_dummy_token_id(),
+        admin: admin(),
+        network: Network::Ethereum,
+        proof_of_sql_commitments: None,
+        gateway: GatewayConfiguration::default(),
+    }
+}
+
+/// Creates a dummy [`Campaign`] that accepts claims at any time, with an optional vesting
+/// schedule.
+fn create_dummy_campaign(vesting: Option<VestingSchedule>) -> Campaign {
+    Campaign {
+        eligibility: Eligibility {
+            snapshot_block: 100,
+            rule: EligibilityRule::minimum_balance(MINIMUM_BALANCE),
+        },
+        amount: AmountPolicy::Fixed(CLAIM_AMOUNT),
+        window: ClaimWindow {
+            start: Timestamp::from(0),
+            end: Timestamp::from(u64::MAX),
+        },
+        vesting,
+    }
+}
+
+/// Creates a dummy [`Campaign`] that pays the amounts in an allowlist `tree`.
+fn create_allowlist_campaign(tree: &AllowlistTree) -> Campaign {
+    let mut campaign = create_dummy_campaign(None);
+    campaign.eligibility.rule =
+        EligibilityRule::Criterion(Criterion::Allowlisted { root: tree.root() });
+    campaign.amount = AmountPolicy::Allowlisted;
+    campaign
+}
+
+/// Creates a dummy [`Campaign`] that pays the Linera `members`.
+fn create_linera_campaign(members: impl IntoIterator<Item = LineraIdentity>) -> Campaign {
+    let mut campaign = create_dummy_campaign(None);
+    campaign.eligibility.rule = EligibilityRule::Criterion(Criterion::LineraMember {
+        members: members.into_iter().collect::<BTreeSet<_>>(),
+    });
+    campaign
+}
+
+/// Creates a dummy [`Campaign`] that pays the users with a `minimum` balance of the
+/// [`linera_token_id`] token.
+fn create_linera_token_campaign(minimum: Amount) -> Campaign {
+    let mut campaign = create_dummy_campaign(None);
+    campaign.eligibility.rule = EligibilityRule::Criterion(Criterion::LineraTokenBalance {
+        token: linera_token_id(),
+        minimum,
+    });
+    campaign
+}
+
+/// Returns the [`ApplicationId`] of the Linera token read by the campaigns created by
+/// [`create_linera_token_campaign`].
+fn linera_token_id() -> ApplicationId<FungibleTokenAbi> {
+    create_dummy_application_id("Linera token", 0)
+}
+
+/// Configures the `contract`'s mock runtime to report the `balance` of the `holder` in the
+/// [`linera_token_id`] token, and to accept the transfers of the airdropped tokens from the
+/// application's account.
+fn expect_linera_token_balance(
+    contract: &mut ApplicationContract,
+    application_id: ApplicationId<ApplicationAbi>,
+    holder: Owner,
+    balance: Amount,
+) {
+    contract.runtime.set_call_application_handler(
+        move |is_authenticated, target_application, operation| {
+            if target_application == create_dummy_token_id() {
+                assert!(is_authenticated);
+                let fungible::Operation::Transfer { owner, .. } =
+                    bcs::from_bytes(&operation).expect("Payment transfer should be deserializable")
+                else {
+                    panic!("Unexpected operation on the airdropped token");
+                };
+                assert_eq!(
+                    owner,
+                    AccountOwner::Application(application_id.forget_abi())
+                );
+
+                return bcs::to_bytes(&FungibleResponse::Ok)
+                    .expect("Unit type should be serializable");
+            }
+
+            assert!(!is_authenticated);
+            assert_eq!(target_application, linera_token_id().forget_abi());
+            assert_eq!(
+                operation,
+                bcs::to_bytes(&fungible::Operation::Balance {
+                    owner: AccountOwner::User(holder),
+                })
+                .expect("Balance query should be serializable")
+            );
+
+            bcs::to_bytes(&FungibleResponse::Balance(balance))
+                .expect("Balance response should be serializable")
+        },
+    );
+}
+
+/// Adds a `campaign` directly to the `contract`'s state, returning its [`CampaignId`].
+fn add_campaign(contract: &mut ApplicationContract, campaign: Campaign) -> CampaignId {
+    let campaign_id = *contract.state.next_campaign_id.get();
+
+    contract
+        .state
+        .next_campaign_id
+        .set(CampaignId(campaign_id.0 + 1));
+    contract
+        .state
+        .campaigns
+        .insert(&campaign_id, campaign)
+        .expect("Failed to write campaign");
+
+    campaign_id
+}
+
+/// Adds `amount` tokens directly to a `campaign`'s budget in the `contract`'s state.
+fn fund_campaign(contract: &mut ApplicationContract, campaign: CampaignId, amount: Amount) {
+    let mut budget = read_budget(contract, campaign);
+
+    budget.funded = budget.funded.saturating_add(amount);
+
+    contract
+        .state
+        .budgets
+        .insert(&campaign, budget)
+        .expect("Failed to write campaign budget");
+}
+
+/// Reads the [`Budget`] of a `campaign` from the `contract`'s state.
+fn read_budget(contract: &ApplicationContract, campaign: CampaignId) -> Budget {
+    contract
+        .state
+        .budgets
+        .get(&campaign)
+        .blocking_wait()
+        .expect("Failed to read campaign budget")
+        .unwrap_or_default()
+}
+
+/// Configures the `contract`'s mock runtime to answer the eligibility query for an `airdrop`.
+fn expect_eligibility_query(
+    contract: &mut ApplicationContract,
+    airdrop: &AirDropId,
+    is_eligible: bool,
+) {
+    let application_id = contract.runtime.application_id();
+    let variables = async_graphql::Variables::from_value(async_graphql::value!({
+        "campaign": airdrop.campaign().0,
+        "address": airdrop.external_address().to_string(),
+        "apiToken": API_TOKEN,
+    }));
+
+    contract.runtime.add_expected_service_query(
+        application_id,
+        async_graphql::Request::new(
+            "query CheckEligibility(\
+                $campaign: CampaignId!, \
+                $address: EthereumAddress!, \
+                $apiToken: String!\
+            ) { \
+                checkEligibility(campaign: $campaign, address: $address, apiToken: $apiToken) { \
+                    eligible \
+                    balance \
+                    block \
+                    matched_rule: matchedRule \
+                    amount \
+                } \
+            }",
+        )
+        .variables(variables),
+        async_graphql::Response::new(IndexMap::from_iter([(
+            async_graphql::Name::new("checkEligibility"),
+            async_graphql::to_value(create_evidence(is_eligible))
+                .expect("Eligibility evidence should be serializable"),
+        )])),
+    );
+}
+
+/// Creates the [`EligibilityEvidence`] returned by the service for a claimer of a campaign
+/// created by [`create_dummy_campaign`].
+fn create_evidence(is_eligible: bool) -> EligibilityEvidence {
+    let campaign = create_dummy_campaign(None);
+
+    EligibilityEvidence {
+        eligible: is_eligible,
+        balance: Some(if is_eligible {
+            MINIMUM_BALANCE
+        } else {
+            U256::ZERO
+        }),
+        block: campaign.eligibility.snapshot_block,
+        matched_rule: is_eligible.then(|| campaign.eligibility.rule.to_string()),
+        amount: if is_eligible {
+            CLAIM_AMOUNT
+        } else {
+            Amount::ZERO
+        },
+    }
+}
+
+/// Reads the [`ClaimRecord`] stored for an `airdrop`, if its claim was approved.
+fn read_claim_record(contract: &ApplicationContract, airdrop: &AirDropId) -> Option<ClaimRecord> {
+    contract
+        .state
+        .handled_airdrops
+        .get(airdrop)
+        .blocking_wait()
+        .expect("Failed to read claim record")
+}
+
+/// Reads the [`RejectedClaim`]s recorded by the contract.
+fn read_rejected_claims(contract: &ApplicationContract) -> Vec<RejectedClaim> {
+    contract
+        .state
+        .rejected_claims
+        .read(..)
+        .blocking_wait()
+        .expect("Failed to read rejected claims")
+}
+
+/// Reads the reasons of the [`RejectedClaim`]s recorded by the contract.
+fn read_rejection_reasons(contract: &ApplicationContract) -> Vec<String> {
+    read_rejected_claims(contract)
+        .into_iter()
+        .map(|rejected_claim| rejected_claim.reason)
+        .collect()
+}
+
+/// Reads the [`ClaimRecord`] stored for an `airdrop` claimed by a Linera identity, if its claim
+/// was approved.
+fn read_linera_claim_record(
+    contract: &ApplicationContract,
+    airdrop: &LineraAirDropId,
+) -> Option<ClaimRecord> {
+    contract
+        .state
+        .linera_airdrops
+        .get(airdrop)
+        .blocking_wait()
+        .expect("Failed to read claim record")
+}
+
+/// The API token used in the tests' claims.
+const API_TOKEN: &str = "API token";
+
+/// Creates a [`ClaimRequest`] for an `airdrop`.
+fn create_claim_request(airdrop: AirDropId, destination: Account) -> ClaimRequest {
+    ClaimRequest {
+        id: airdrop,
+        destination,
+        api_token: API_TOKEN.to_owned(),
+        relayer_fee: None,
+        proven_balance: None,
+        allowlist_proof: None,
+    }
+}
+
+/// Creates a [`LineraClaimRequest`] for a `campaign`, signed by an optional `owner` and submitted
+/// from the `destination`'s chain.
+fn create_linera_claim_request(
+    campaign: CampaignId,
+    owner: Option<Owner>,
+    destination: Account,
+) -> LineraClaimRequest {
+    LineraClaimRequest {
+        campaign,
+        owner,
+        chain_id: destination.chain_id,
+        destination,
+    }
+}
+
+/// Creates a dummy [`Account`] to use as a test destination for the airdropped tokens.
+fn create_dummy_destination(index: usize) -> Account {
+    Account {
+        chain_id: ChainId(CryptoHash::test_hash(format!("destination chain {index}"))),
+        owner: AccountOwner::User(Owner(CryptoHash::test_hash(format!(
+            "destination owner {index}"
         )))),
     }
 }
+
+/// Creates a dummy [`MessageId`] for a message sent from `chain_id`.
+fn create_dummy_message_id(chain_id: ChainId) -> MessageId {
+    MessageId {
+        chain_id,
+        height: BlockHeight::ZERO,
+        index: 0,
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A command-line tool that renders and checks the application's parameters and campaigns
+//! before they are deployed.
+
+#[cfg(test)]
+mod deployment_unit_tests;
+
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use airdrop_demo::{
+    gateway::GatewayConfiguration, network::Network, Campaign, Criterion, EligibilityRule,
+    LineraIdentity, Parameters,
+};
+use anyhow::{ensure, Context};
+use clap::{Parser, Subcommand};
+use linera_sdk::{
+    base::{AccountOwner, Amount, ApplicationId, Owner},
+    serde_json,
+};
+
+/// Renders and checks the configuration of the airdrop application.
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Writes the application's parameters to a JSON file, and prints the
+    /// `--json-parameters-path` for `linera project publish-and-create`, together with the
+    /// `--required-application-ids` that the token application must be part of.
+    Render {
+        /// The ID of the fungible token application with the airdropped tokens.
+        #[arg(long)]
+        token_id: ApplicationId,
+        /// The owner allowed to create campaigns and update the denylist.
+        #[arg(long)]
+        admin: Owner,
+        /// The network read by the campaigns' rules: `ethereum`, `polygon`, `arbitrum`, `base`
+        /// or `SCHEMA:CHAIN_ID`.
+        #[arg(long, default_value = "ethereum")]
+        network: Network,
+        /// The URL of the Space-and-Time Gateway's SQL API, if not the default one.
+        #[arg(long)]
+        gateway_url: Option<String>,
+        /// A file with the BCS serialized Proof-of-SQL commitments, to accept balance proofs.
+        #[arg(long)]
+        proof_of_sql_commitments: Option<PathBuf>,
+        /// The JSON file to write the parameters to.
+        #[arg(long, default_value = "parameters.json")]
+        output: PathBuf,
+    },
+    /// Checks a JSON file with the application's parameters.
+    Check {
+        /// The JSON file with the parameters.
+        parameters: PathBuf,
+    },
+    /// Checks a JSON file with a campaign to be created.
+    CheckCampaign {
+        /// The JSON file with the campaign.
+        campaign: PathBuf,
+        /// The network configured in the application's parameters.
+        #[arg(long, default_value = "ethereum")]
+        network: Network,
+        /// The latest block of the `network`, which the campaign's snapshot blocks on that
+        /// network must not be after.
+        #[arg(long)]
+        latest_block: Option<u64>,
+    },
+    /// Renders a `LineraMember` eligibility rule with the users that hold a minimum balance in
+    /// a snapshot of a Linera fungible token's accounts.
+    ///
+    /// Accounts owned by applications are skipped, since applications can't sign claims.
+    LineraMembers {
+        /// The JSON file with the token service's response to
+        /// `query { accounts { entries { key value } } }`.
+        accounts: PathBuf,
+        /// The minimum balance a user must hold to be a member.
+        #[arg(long, default_value = "0.000000000000000001")]
+        minimum: Amount,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    match Cli::parse().command {
+        Command::Render {
+            token_id,
+            admin,
+            network,
+            gateway_url,
+            proof_of_sql_commitments,
+            output,
+        } => {
+            let mut gateway = GatewayConfiguration::default();
+            if let Some(url) = gateway_url {
+                gateway.url = url;
+            }
+
+            let proof_of_sql_commitments = proof_of_sql_commitments
+                .map(|path| {
+                    fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))
+                })
+                .transpose()?;
+
+            let parameters = Parameters {
+                token_id: token_id.with_abi(),
+                admin,
+                network,
+                proof_of_sql_commitments,
+                gateway,
+            };
+
+            println!("{}", render(&parameters, &output)?);
+        }
+        Command::Check { parameters } => {
+            let parameters = serde_json::from_str::<Parameters>(&read_file(&parameters)?)
+                .context("Failed to deserialize the parameters")?;
+
+            parameters
+                .validate()
+                .context("Invalid application parameters")?;
+
+            println!(
+                "Parameters are valid. The application must be created with \
+                `--required-application-ids {}`",
+                parameters.token_id.forget_abi()
+            );
+        }
+        Command::CheckCampaign {
+            campaign,
+            network,
+            latest_block,
+        } => {
+            check_campaign(&read_file(&campaign)?, &network, latest_block)?;
+
+            println!("Campaign is valid");
+        }
+        Command::LineraMembers { accounts, minimum } => {
+            let rule = linera_members(&read_file(&accounts)?, minimum)?;
+
+            println!("{}", serde_json::to_string(&rule)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the `parameters` and writes them to the JSON file at the `output` path, returning
+/// the arguments for `linera project publish-and-create`.
+fn render(parameters: &Parameters, output: &Path) -> anyhow::Result<String> {
+    parameters
+        .validate()
+        .context("Invalid application parameters")?;
+
+    fs::write(output, serde_json::to_string(parameters)?)
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+
+    Ok(format!(
+        "--json-parameters-path {} --required-application-ids {}",
+        shell_quote(&output.display().to_string()),
+        parameters.token_id.forget_abi()
+    ))
+}
+
+/// Validates the JSON of a `campaign`, checking that its snapshot blocks on the application's
+/// `network` aren't after the `latest_block`, if it's known.
+fn check_campaign(
+    campaign: &str,
+    network: &Network,
+    latest_block: Option<u64>,
+) -> anyhow::Result<()> {
+    let campaign =
+        serde_json::from_str::<Campaign>(campaign).context("Failed to deserialize the campaign")?;
+
+    campaign.validate().context("Invalid campaign")?;
+
+    if let Some(latest_block) = latest_block {
+        for (snapshot_network, snapshot_block) in campaign.snapshot_blocks() {
+            if snapshot_network.unwrap_or(network) == network {
+                ensure!(
+                    snapshot_block <= latest_block,
+                    "Snapshot block {snapshot_block} on {network} is after the latest block \
+                    {latest_block}"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds a `LineraMember` eligibility rule with the users that hold at least the `minimum`
+/// balance in the JSON response of a fungible token service to
+/// `query { accounts { entries { key value } } }`.
+fn linera_members(accounts: &str, minimum: Amount) -> anyhow::Result<EligibilityRule> {
+    let response = serde_json::from_str::<serde_json::Value>(accounts)
+        .context("Failed to deserialize the accounts")?;
+    let entries = response
+        .pointer("/data/accounts/entries")
+        .or_else(|| response.pointer("/accounts/entries"))
+        .and_then(|entries| entries.as_array())
+        .context("Accounts response has no `accounts.entries` list")?;
+
+    let mut members = BTreeSet::new();
+    for entry in entries {
+        let owner = serde_json::from_value::<AccountOwner>(entry["key"].clone())
+            .with_context(|| format!("Invalid account owner in {entry}"))?;
+        let balance = entry["value"]
+            .as_str()
+            .and_then(|balance| balance.parse::<Amount>().ok())
+            .with_context(|| format!("Invalid balance in {entry}"))?;
+
+        if let AccountOwner::User(owner) = owner {
+            if balance >= minimum {
+                members.insert(LineraIdentity::Owner(owner));
+            }
+        }
+    }
+
+    ensure!(!members.is_empty(), "No user holds the minimum balance");
+
+    Ok(EligibilityRule::Criterion(Criterion::LineraMember {
+        members,
+    }))
+}
+
+/// Quotes an `argument` so that a POSIX shell reads it as a single word.
+fn shell_quote(argument: &str) -> String {
+    format!("'{}'", argument.replace('\'', r"'\''"))
+}
+
+/// Reads a file to a string, with the file's path in the error.
+fn read_file(path: &Path) -> anyhow::Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
@@ -0,0 +1,288 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeSet, fs};
+
+use airdrop_demo::{
+    gateway::GatewayConfiguration,
+    network::{Network, NetworkSnapshot},
+    test_utils::{create_dummy_application_id, create_dummy_token_id, CampaignBuilder},
+    AmountPolicy, Campaign, Criterion, EligibilityRule, LineraIdentity, Parameters,
+};
+use alloy_primitives::U256;
+use linera_sdk::{
+    base::{AccountOwner, Amount, CryptoHash, Owner},
+    serde_json,
+};
+
+use super::{check_campaign, linera_members, render};
+
+/// Tests if the rendered command reads the parameters from a file that they round-trip through,
+/// even with quotes in the query templates and in the file's path.
+#[test]
+fn renders_parameters_file() {
+    let parameters = create_parameters();
+    let directory =
+        std::env::temp_dir().join(format!("airdrop deployment's test {}", std::process::id()));
+    fs::create_dir_all(&directory).expect("Failed to create output directory");
+    let output = directory.join("parameters.json");
+
+    let command = render(&parameters, &output).expect("Failed to render parameters");
+    let arguments = split_shell_words(&command);
+
+    assert_eq!(
+        arguments,
+        [
+            "--json-parameters-path".to_owned(),
+            output.display().to_string(),
+            "--required-application-ids".to_owned(),
+            parameters.token_id.forget_abi().to_string(),
+        ]
+    );
+
+    let written = fs::read_to_string(&output).expect("Failed to read parameters file");
+    fs::remove_dir_all(&directory).expect("Failed to remove output directory");
+
+    let written_parameters =
+        serde_json::from_str::<Parameters>(&written).expect("Invalid parameters file");
+
+    assert!(written.contains("'{address}'"));
+    assert_eq!(
+        serde_json::to_value(written_parameters).expect("`Parameters` should be serializable"),
+        serde_json::to_value(parameters).expect("`Parameters` should be serializable")
+    );
+}
+
+/// Tests if invalid parameters aren't rendered.
+#[test]
+fn rejects_rendering_invalid_parameters() {
+    let mut parameters = create_parameters();
+    parameters.gateway.url = "api.spaceandtime.dev".to_owned();
+    let output = std::env::temp_dir().join(format!(
+        "airdrop-invalid-parameters-{}.json",
+        std::process::id()
+    ));
+
+    let error = render(&parameters, &output).expect_err("Invalid parameters should be rejected");
+
+    assert_eq!(
+        format!("{error:#}"),
+        "Invalid application parameters: Gateway URL must be an HTTP or HTTPS URL"
+    );
+    assert!(!output.exists());
+}
+
+/// Tests if a valid campaign is accepted, with or without the latest block.
+#[test]
+fn accepts_valid_campaign() {
+    let campaign = campaign_json(CampaignBuilder::minimum_balance(100, U256::from(1)).build());
+
+    for latest_block in [None, Some(100), Some(1_000)] {
+        check_campaign(&campaign, &Network::Ethereum, latest_block)
+            .expect("Valid campaign should be accepted");
+    }
+}
+
+/// Tests if a campaign that isn't valid JSON, or that fails its validation, is rejected.
+#[test]
+fn rejects_invalid_campaign() {
+    let zero_amount = campaign_json(
+        CampaignBuilder::minimum_balance(100, U256::from(1))
+            .with_amount(AmountPolicy::Fixed(Amount::ZERO))
+            .build(),
+    );
+
+    let cases = [
+        ("{}".to_owned(), "Failed to deserialize the campaign"),
+        (
+            zero_amount,
+            "Invalid campaign: Campaign must airdrop a non-zero amount",
+        ),
+    ];
+
+    for (campaign, expected_error) in cases {
+        let error = check_campaign(&campaign, &Network::Ethereum, None)
+            .expect_err("Invalid campaign should be rejected");
+
+        assert!(
+            format!("{error:#}").starts_with(expected_error),
+            "Unexpected error: {error:#}"
+        );
+    }
+}
+
+/// Tests if only the snapshot blocks on the configured network are checked against its latest
+/// block.
+#[test]
+fn checks_snapshot_blocks_on_configured_network() {
+    let campaign = campaign_json(combined_balance_campaign(Network::Polygon));
+
+    check_campaign(&campaign, &Network::Ethereum, Some(200))
+        .expect("Snapshot on another network should not be checked");
+
+    let error = check_campaign(&campaign, &Network::Polygon, Some(200))
+        .expect_err("Snapshot after the latest block should be rejected");
+
+    assert_eq!(
+        error.to_string(),
+        "Snapshot block 500 on polygon is after the latest block 200"
+    );
+}
+
+/// Tests if a combined balance snapshot on a network with an invalid schema is rejected.
+#[test]
+fn rejects_campaign_with_invalid_snapshot_network() {
+    let campaign = campaign_json(combined_balance_campaign(Network::Other {
+        schema: "BAD SCHEMA".to_owned(),
+        chain_id: 5,
+    }));
+
+    let error = check_campaign(&campaign, &Network::Ethereum, None)
+        .expect_err("Invalid snapshot network should be rejected");
+
+    assert_eq!(
+        format!("{error:#}"),
+        "Invalid campaign: Combined balance snapshot is invalid: Network schema must be a \
+        non-empty alphanumeric identifier"
+    );
+}
+
+/// Tests if the members are the users holding at least the `--minimum`, skipping applications,
+/// with or without the response's `data` wrapper.
+#[test]
+fn renders_members_above_minimum() {
+    let rich_user = Owner(CryptoHash::test_hash("rich user"));
+    let minimum_user = Owner(CryptoHash::test_hash("minimum user"));
+    let poor_user = Owner(CryptoHash::test_hash("poor user"));
+    let application =
+        AccountOwner::Application(create_dummy_application_id("membership", 0).forget_abi());
+
+    let entries = serde_json::json!([
+        account_entry(AccountOwner::User(rich_user), "100."),
+        account_entry(AccountOwner::User(minimum_user), "10."),
+        account_entry(AccountOwner::User(poor_user), "9.999"),
+        account_entry(application, "1000."),
+    ]);
+    let expected_rule = EligibilityRule::Criterion(Criterion::LineraMember {
+        members: BTreeSet::from([
+            LineraIdentity::Owner(rich_user),
+            LineraIdentity::Owner(minimum_user),
+        ]),
+    });
+
+    for accounts in [
+        serde_json::json!({ "data": { "accounts": { "entries": entries } } }),
+        serde_json::json!({ "accounts": { "entries": entries } }),
+    ] {
+        let rule = linera_members(&accounts.to_string(), Amount::from_tokens(10))
+            .expect("Failed to render members");
+
+        assert_eq!(rule, expected_rule);
+    }
+}
+
+/// Tests if malformed account snapshots, and snapshots where no user holds the minimum, are
+/// rejected.
+#[test]
+fn rejects_invalid_accounts() {
+    let user = AccountOwner::User(Owner(CryptoHash::test_hash("user")));
+
+    let cases = [
+        ("not JSON".to_owned(), "Failed to deserialize the accounts"),
+        (
+            serde_json::json!({ "data": { "accounts": {} } }).to_string(),
+            "Accounts response has no `accounts.entries` list",
+        ),
+        (
+            accounts_json(serde_json::json!({ "key": "nobody", "value": "1." })),
+            "Invalid account owner in ",
+        ),
+        (
+            accounts_json(serde_json::json!({ "key": user, "value": "lots" })),
+            "Invalid balance in ",
+        ),
+        (
+            accounts_json(account_entry(user, "1.")),
+            "No user holds the minimum balance",
+        ),
+    ];
+
+    for (accounts, expected_error) in cases {
+        let error = linera_members(&accounts, Amount::from_tokens(10))
+            .expect_err("Invalid accounts should be rejected");
+
+        assert!(
+            error.to_string().starts_with(expected_error),
+            "Unexpected error for {accounts}: {error}"
+        );
+    }
+}
+
+/// Creates valid [`Parameters`] with the default Gateway configuration.
+fn create_parameters() -> Parameters {
+    Parameters {
+        token_id: create_dummy_token_id(),
+        admin: Owner(CryptoHash::test_hash("admin")),
+        network: Network::Ethereum,
+        proof_of_sql_commitments: None,
+        gateway: GatewayConfiguration::default(),
+    }
+}
+
+/// Creates a [`Campaign`] with a combined balance criterion with a snapshot at block 500 on the
+/// `network`.
+fn combined_balance_campaign(network: Network) -> Campaign {
+    let rule = EligibilityRule::Criterion(Criterion::CombinedBalance {
+        minimum: U256::from(1),
+        snapshots: vec![NetworkSnapshot {
+            network,
+            block: 500,
+        }],
+    });
+
+    CampaignBuilder::new(100, rule).build()
+}
+
+/// Serializes a [`Campaign`] to the JSON read by `check-campaign`.
+fn campaign_json(campaign: Campaign) -> String {
+    serde_json::to_string(&campaign).expect("`Campaign` should be serializable")
+}
+
+/// Creates an entry of the token service's `accounts` response.
+fn account_entry(owner: AccountOwner, balance: &str) -> serde_json::Value {
+    serde_json::json!({ "key": owner, "value": balance })
+}
+
+/// Creates the token service's `accounts` response with a single `entry`.
+fn accounts_json(entry: serde_json::Value) -> String {
+    serde_json::json!({ "data": { "accounts": { "entries": [entry] } } }).to_string()
+}
+
+/// Splits a command line into words like a POSIX shell, for arguments without variables.
+fn split_shell_words(command: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = None::<String>;
+    let mut characters = command.chars();
+    let mut is_quoted = false;
+
+    while let Some(character) = characters.next() {
+        match character {
+            '\'' => {
+                is_quoted = !is_quoted;
+                word.get_or_insert_with(String::new);
+            }
+            '\\' if !is_quoted => {
+                let escaped = characters.next().expect("Escape at the end of the command");
+                word.get_or_insert_with(String::new).push(escaped);
+            }
+            character if character.is_whitespace() && !is_quoted => {
+                words.extend(word.take());
+            }
+            character => word.get_or_insert_with(String::new).push(character),
+        }
+    }
+
+    assert!(!is_quoted, "Unterminated quote in {command}");
+    words.extend(word);
+    words
+}
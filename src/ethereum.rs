@@ -0,0 +1,244 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! GraphQL scalars for the Ethereum types used by the claims and the campaigns.
+
+use std::str::FromStr;
+
+use alloy_primitives::{Address, Signature, B256, U256};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// An Ethereum address.
+///
+/// In GraphQL, it's a hexadecimal string with 20 bytes, displayed with its [EIP-55] checksum.
+/// Addresses with mixed-case letters must have a valid EIP-55 checksum.
+///
+/// Scalars can't read the application's parameters, so the addresses that are displayed with the
+/// checksum of the configured network are resolved from the GraphQL context instead, as in
+/// [`AirDropId`][crate::AirDropId], and parsed back with that checksum by
+/// [`AirDropIdInput`][crate::graphql::AirDropIdInput].
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(transparent)]
+pub struct EthereumAddress(pub Address);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for EthereumAddress {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let async_graphql::Value::String(address_string) = &value else {
+            return Err(async_graphql::InputValueError::expected_type(value));
+        };
+
+        parse_address(address_string, None)
+            .map(EthereumAddress)
+            .map_err(async_graphql::InputValueError::custom)
+    }
+
+    fn is_valid(value: &async_graphql::Value) -> bool {
+        matches!(value, async_graphql::Value::String(_))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(self.0.to_checksum(None))
+    }
+}
+
+/// Parses a hexadecimal Ethereum address, optionally prefixed with `0x`.
+///
+/// Addresses with mixed-case letters must have a valid [EIP-55] checksum, or the [EIP-1191]
+/// checksum of the `checksum_chain_id` if there is one.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+/// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+pub fn parse_address(
+    address_string: &str,
+    checksum_chain_id: Option<u64>,
+) -> Result<Address, &'static str> {
+    let hex_digits = address_string.strip_prefix("0x").unwrap_or(address_string);
+    let address = Address::from_str(hex_digits)
+        .map_err(|_| "Ethereum address must be a hexadecimal string with 20 bytes")?;
+
+    let is_mixed_case = hex_digits.chars().any(|digit| digit.is_ascii_uppercase())
+        && hex_digits.chars().any(|digit| digit.is_ascii_lowercase());
+    let has_checksum = |chain_id| address.to_checksum(chain_id)[2..] == *hex_digits;
+
+    if is_mixed_case
+        && !has_checksum(None)
+        && !checksum_chain_id.is_some_and(|chain_id| has_checksum(Some(chain_id)))
+    {
+        return Err("Ethereum address has an invalid checksum");
+    }
+
+    Ok(address)
+}
+
+impl From<Address> for EthereumAddress {
+    fn from(address: Address) -> Self {
+        EthereumAddress(address)
+    }
+}
+
+impl From<EthereumAddress> for Address {
+    fn from(address: EthereumAddress) -> Self {
+        address.0
+    }
+}
+
+impl std::fmt::Display for EthereumAddress {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+/// An Ethereum signature that recovers its signer's address.
+///
+/// In GraphQL, it's a hexadecimal string with the 65 bytes of the `r`, `s` and `v` values, as
+/// returned by wallets.
+///
+/// The signature's parity is always stored as a boolean, which is how signatures are created, so
+/// that parsed and deserialized signatures are equal to the ones they were created from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct EthereumSignature(pub Signature);
+
+#[async_graphql::Scalar(name = "Signature")]
+impl async_graphql::ScalarType for EthereumSignature {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let async_graphql::Value::String(signature_string) = &value else {
+            return Err(async_graphql::InputValueError::expected_type(value));
+        };
+
+        let signature = Signature::from_str(signature_string).map_err(|_| {
+            async_graphql::InputValueError::custom(
+                "Signature must be a hexadecimal string with 65 bytes",
+            )
+        })?;
+
+        Ok(signature.into())
+    }
+
+    fn is_valid(value: &async_graphql::Value) -> bool {
+        matches!(value, async_graphql::Value::String(_))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(format!("0x{}", hex::encode(self.0.as_bytes())))
+    }
+}
+
+impl<'de> Deserialize<'de> for EthereumSignature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Signature::deserialize(deserializer).map(EthereumSignature::from)
+    }
+}
+
+impl From<Signature> for EthereumSignature {
+    fn from(signature: Signature) -> Self {
+        EthereumSignature(signature.with_parity_bool())
+    }
+}
+
+impl From<EthereumSignature> for Signature {
+    fn from(signature: EthereumSignature) -> Self {
+        signature.0
+    }
+}
+
+/// A 32-byte hash, such as the root of an allowlist or the nodes of its Merkle proofs.
+///
+/// In GraphQL, it's a hexadecimal string with 32 bytes, prefixed with `0x`.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(transparent)]
+pub struct EthereumHash(pub B256);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for EthereumHash {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let async_graphql::Value::String(hash_string) = &value else {
+            return Err(async_graphql::InputValueError::expected_type(value));
+        };
+
+        let hash = B256::from_str(hash_string).map_err(|_| {
+            async_graphql::InputValueError::custom(
+                "Hash must be a hexadecimal string with 32 bytes",
+            )
+        })?;
+
+        Ok(EthereumHash(hash))
+    }
+
+    fn is_valid(value: &async_graphql::Value) -> bool {
+        matches!(value, async_graphql::Value::String(_))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(self.0.to_string())
+    }
+}
+
+impl From<B256> for EthereumHash {
+    fn from(hash: B256) -> Self {
+        EthereumHash(hash)
+    }
+}
+
+impl From<EthereumHash> for B256 {
+    fn from(hash: EthereumHash) -> Self {
+        hash.0
+    }
+}
+
+/// A 256-bit unsigned integer, such as a token balance.
+///
+/// In GraphQL, it's a hexadecimal string prefixed with `0x`, as it's serialized, and it can also
+/// be parsed from a decimal string.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(transparent)]
+pub struct Uint256(pub U256);
+
+#[async_graphql::Scalar(name = "U256")]
+impl async_graphql::ScalarType for Uint256 {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let async_graphql::Value::String(integer_string) = &value else {
+            return Err(async_graphql::InputValueError::expected_type(value));
+        };
+
+        let integer = U256::from_str(integer_string).map_err(|_| {
+            async_graphql::InputValueError::custom(
+                "U256 must be a decimal string or a hexadecimal string prefixed with `0x`",
+            )
+        })?;
+
+        Ok(Uint256(integer))
+    }
+
+    fn is_valid(value: &async_graphql::Value) -> bool {
+        matches!(value, async_graphql::Value::String(_))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(format!("{:#x}", self.0))
+    }
+}
+
+impl From<U256> for Uint256 {
+    fn from(integer: U256) -> Self {
+        Uint256(integer)
+    }
+}
+
+impl From<Uint256> for U256 {
+    fn from(integer: Uint256) -> Self {
+        integer.0
+    }
+}
@@ -0,0 +1,358 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configuration of the Space-and-Time Gateway used to check the claimers' eligibility.
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::network::Network;
+
+/// The Space-and-Time Gateway endpoint and the queries used to read the claimers' balances.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GatewayConfiguration {
+    /// The URL of the Gateway's SQL API.
+    pub url: String,
+    /// The SQL query that reads the balance of an address at a snapshot block.
+    pub query_template: QueryTemplate,
+    /// The SQL query that reads the lowest balance held by an address from a start block up to
+    /// the snapshot block.
+    pub holding_query_template: QueryTemplate,
+    /// The SQL query that reads the time-weighted average balance of an address from a start
+    /// block up to the snapshot block.
+    pub average_query_template: QueryTemplate,
+    /// The SQL query that reads the balance of an ERC-20 token contract held by an address at a
+    /// snapshot block.
+    pub token_balance_query_template: QueryTemplate,
+    /// The SQL query that counts the transactions sent by an address up to the snapshot block.
+    pub transaction_count_query_template: QueryTemplate,
+    /// The SQL query that counts the transactions sent by an address to a contract up to the
+    /// snapshot block.
+    pub contract_interaction_query_template: QueryTemplate,
+    /// The SQL query that reads the block of an address's first transaction, up to the snapshot
+    /// block.
+    pub first_seen_query_template: QueryTemplate,
+    /// The name of the column with the balance in the query's result.
+    pub balance_column: String,
+}
+
+impl Default for GatewayConfiguration {
+    fn default() -> Self {
+        GatewayConfiguration {
+            url: "https://api.spaceandtime.dev/v1/sql".to_owned(),
+            query_template: QueryTemplate::new(
+                "SELECT BALANCE FROM {network}.NATIVE_WALLETS \
+                WHERE WALLET_ADDRESS = '{address}' AND BLOCK_NUMBER <= {snapshot_block} \
+                ORDER BY BLOCK_NUMBER DESC \
+                LIMIT 1;"
+                    .to_owned(),
+                &[],
+            )
+            .expect("Default query template should be valid"),
+            // The balance held at the start block is the one from the last change at or before
+            // it, so that change is included in the range.
+            holding_query_template: QueryTemplate::new(
+                "SELECT MIN(BALANCE) AS BALANCE FROM {network}.NATIVE_WALLETS \
+                WHERE WALLET_ADDRESS = '{address}' AND BLOCK_NUMBER <= {snapshot_block} \
+                AND BLOCK_NUMBER >= (\
+                    SELECT MAX(BLOCK_NUMBER) FROM {network}.NATIVE_WALLETS \
+                    WHERE WALLET_ADDRESS = '{address}' AND BLOCK_NUMBER <= {start_block}\
+                );"
+                .to_owned(),
+                &[QueryTemplate::START_BLOCK],
+            )
+            .expect("Default holding query template should be valid"),
+            // Each balance is weighted by the number of blocks in the period until the next
+            // change, and blocks before the first change count as a zero balance.
+            average_query_template: QueryTemplate::new(
+                "SELECT SUM(BALANCE * (\
+                    LEAST(NEXT_BLOCK, {snapshot_block} + 1) \
+                    - GREATEST(BLOCK_NUMBER, {start_block})\
+                )) / ({snapshot_block} + 1 - {start_block}) AS BALANCE \
+                FROM (\
+                    SELECT BALANCE, BLOCK_NUMBER, \
+                    LEAD(BLOCK_NUMBER, 1, {snapshot_block} + 1) \
+                    OVER (ORDER BY BLOCK_NUMBER) AS NEXT_BLOCK \
+                    FROM {network}.NATIVE_WALLETS \
+                    WHERE WALLET_ADDRESS = '{address}' AND BLOCK_NUMBER <= {snapshot_block}\
+                ) AS HISTORY \
+                WHERE NEXT_BLOCK > {start_block};"
+                    .to_owned(),
+                &[QueryTemplate::START_BLOCK],
+            )
+            .expect("Default average query template should be valid"),
+            token_balance_query_template: QueryTemplate::new(
+                "SELECT BALANCE FROM {network}.FUNGIBLETOKEN_WALLET \
+                WHERE WALLET_ADDRESS = '{address}' AND TOKEN_ADDRESS = '{contract}' \
+                AND BLOCK_NUMBER <= {snapshot_block} \
+                ORDER BY BLOCK_NUMBER DESC \
+                LIMIT 1;"
+                    .to_owned(),
+                &[QueryTemplate::CONTRACT],
+            )
+            .expect("Default token balance query template should be valid"),
+            transaction_count_query_template: QueryTemplate::new(
+                "SELECT COUNT(*) AS TRANSACTION_COUNT FROM {network}.TRANSACTIONS \
+                WHERE FROM_ADDRESS = '{address}' AND BLOCK_NUMBER <= {snapshot_block};"
+                    .to_owned(),
+                &[],
+            )
+            .expect("Default transaction count query template should be valid"),
+            contract_interaction_query_template: QueryTemplate::new(
+                "SELECT COUNT(*) AS INTERACTION_COUNT FROM {network}.TRANSACTIONS \
+                WHERE FROM_ADDRESS = '{address}' AND TO_ADDRESS = '{contract}' \
+                AND BLOCK_NUMBER <= {snapshot_block};"
+                    .to_owned(),
+                &[QueryTemplate::CONTRACT],
+            )
+            .expect("Default contract interaction query template should be valid"),
+            first_seen_query_template: QueryTemplate::new(
+                "SELECT MIN(BLOCK_NUMBER) AS FIRST_SEEN_BLOCK FROM {network}.TRANSACTIONS \
+                WHERE (FROM_ADDRESS = '{address}' OR TO_ADDRESS = '{address}') \
+                AND BLOCK_NUMBER <= {snapshot_block};"
+                    .to_owned(),
+                &[],
+            )
+            .expect("Default first seen query template should be valid"),
+            balance_column: "BALANCE".to_owned(),
+        }
+    }
+}
+
+impl GatewayConfiguration {
+    /// Returns the name of each query template, together with the template and the placeholders
+    /// that its query fills in besides the address, the snapshot block and the network.
+    pub fn query_templates(&self) -> [(&'static str, &QueryTemplate, &'static [&'static str]); 7] {
+        [
+            ("query_template", &self.query_template, &[]),
+            (
+                "holding_query_template",
+                &self.holding_query_template,
+                &[QueryTemplate::START_BLOCK],
+            ),
+            (
+                "average_query_template",
+                &self.average_query_template,
+                &[QueryTemplate::START_BLOCK],
+            ),
+            (
+                "token_balance_query_template",
+                &self.token_balance_query_template,
+                &[QueryTemplate::CONTRACT],
+            ),
+            (
+                "transaction_count_query_template",
+                &self.transaction_count_query_template,
+                &[],
+            ),
+            (
+                "contract_interaction_query_template",
+                &self.contract_interaction_query_template,
+                &[QueryTemplate::CONTRACT],
+            ),
+            (
+                "first_seen_query_template",
+                &self.first_seen_query_template,
+                &[],
+            ),
+        ]
+    }
+}
+
+/// An SQL query template with named placeholders for the address and the snapshot block.
+///
+/// The template must contain the [`QueryTemplate::ADDRESS`] and
+/// [`QueryTemplate::SNAPSHOT_BLOCK`] placeholders, and may contain the
+/// [`QueryTemplate::NETWORK`] placeholder. Templates for queries over a holding period must also
+/// contain the [`QueryTemplate::START_BLOCK`] placeholder, and templates for queries on token
+/// balances or contract interactions the [`QueryTemplate::CONTRACT`] placeholder. Other
+/// templates can't contain these placeholders, because their queries don't fill them in. No
+/// other placeholders are allowed.
+///
+/// Deserialized templates are only checked when the [`Parameters`] are validated, because the
+/// placeholders they require depend on the query they are used for.
+///
+/// [`Parameters`]: crate::Parameters
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct QueryTemplate(String);
+
+impl QueryTemplate {
+    /// The placeholder for the Space-and-Time schema of the queried [`Network`].
+    pub const NETWORK: &'static str = "network";
+    /// The placeholder for the lowercase hexadecimal address, prefixed with `0x`.
+    pub const ADDRESS: &'static str = "address";
+    /// The placeholder for the snapshot block number.
+    pub const SNAPSHOT_BLOCK: &'static str = "snapshot_block";
+    /// The placeholder for the first block of a holding period.
+    pub const START_BLOCK: &'static str = "start_block";
+    /// The placeholder for the lowercase hexadecimal contract address, prefixed with `0x`.
+    pub const CONTRACT: &'static str = "contract";
+
+    /// Validates and creates a new [`QueryTemplate`] for a query that fills in the
+    /// `placeholders` besides the address, the snapshot block and the network.
+    pub fn new(
+        template: String,
+        placeholders: &[&'static str],
+    ) -> Result<Self, QueryTemplateError> {
+        let template = QueryTemplate(template);
+        template.validate(placeholders)?;
+        Ok(template)
+    }
+
+    /// Checks that the template contains the address and snapshot block placeholders and all of
+    /// the `placeholders` that its query fills in, and no placeholders that are left unfilled.
+    pub fn validate(&self, placeholders: &[&'static str]) -> Result<(), QueryTemplateError> {
+        let mut found = Vec::with_capacity(placeholders.len() + 2);
+        let mut remaining = self.0.as_str();
+
+        while let Some(start) = remaining.find('{') {
+            let after_brace = &remaining[start + 1..];
+            let end = after_brace
+                .find('}')
+                .ok_or(QueryTemplateError::UnterminatedPlaceholder)?;
+
+            match &after_brace[..end] {
+                Self::NETWORK => {}
+                name @ (Self::ADDRESS | Self::SNAPSHOT_BLOCK) => found.push(name),
+                name @ (Self::START_BLOCK | Self::CONTRACT)
+                    if placeholders.iter().any(|placeholder| *placeholder == name) =>
+                {
+                    found.push(name)
+                }
+                name @ (Self::START_BLOCK | Self::CONTRACT) => {
+                    return Err(QueryTemplateError::UnsupportedPlaceholder(name.to_owned()))
+                }
+                unknown => return Err(QueryTemplateError::UnknownPlaceholder(unknown.to_owned())),
+            }
+
+            remaining = &after_brace[end + 1..];
+        }
+
+        let required = [Self::ADDRESS, Self::SNAPSHOT_BLOCK];
+
+        if let Some(missing) = required
+            .iter()
+            .chain(placeholders)
+            .copied()
+            .find(|placeholder| !found.contains(placeholder))
+        {
+            return Err(QueryTemplateError::MissingPlaceholder(missing));
+        }
+
+        Ok(())
+    }
+
+    /// Renders the SQL query with the placeholders filled in with the `arguments`.
+    ///
+    /// Placeholders without an argument are left unchanged.
+    pub fn render(&self, arguments: &QueryArguments) -> String {
+        let mut query = self
+            .0
+            .replace(
+                &format!("{{{}}}", Self::NETWORK),
+                arguments.network.schema(),
+            )
+            .replace(
+                &format!("{{{}}}", Self::ADDRESS),
+                &to_lowercase_hex(arguments.address),
+            )
+            .replace(
+                &format!("{{{}}}", Self::SNAPSHOT_BLOCK),
+                &arguments.snapshot_block.to_string(),
+            );
+
+        if let Some(start_block) = arguments.start_block {
+            query = query.replace(
+                &format!("{{{}}}", Self::START_BLOCK),
+                &start_block.to_string(),
+            );
+        }
+
+        if let Some(contract) = arguments.contract {
+            query = query.replace(
+                &format!("{{{}}}", Self::CONTRACT),
+                &to_lowercase_hex(contract),
+            );
+        }
+
+        query
+    }
+}
+
+/// The values used to fill in the placeholders of a [`QueryTemplate`].
+#[derive(Clone, Copy, Debug)]
+pub struct QueryArguments<'network> {
+    pub network: &'network Network,
+    pub address: Address,
+    pub snapshot_block: u64,
+    pub start_block: Option<u64>,
+    pub contract: Option<Address>,
+}
+
+impl<'network> QueryArguments<'network> {
+    /// Creates the [`QueryArguments`] for an `address` at a `snapshot_block` on a `network`.
+    pub fn new(network: &'network Network, address: Address, snapshot_block: u64) -> Self {
+        QueryArguments {
+            network,
+            address,
+            snapshot_block,
+            start_block: None,
+            contract: None,
+        }
+    }
+
+    /// Sets the first block of the holding period up to the snapshot block.
+    pub fn with_start_block(mut self, start_block: u64) -> Self {
+        self.start_block = Some(start_block);
+        self
+    }
+
+    /// Sets the token or the contract that is queried.
+    pub fn with_contract(mut self, contract: Address) -> Self {
+        self.contract = Some(contract);
+        self
+    }
+}
+
+/// Formats an [`Address`] as lowercase hexadecimal, prefixed with `0x`.
+fn to_lowercase_hex(address: Address) -> String {
+    format!("0x{}", hex::encode(address))
+}
+
+/// Errors that make a [`QueryTemplate`] invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QueryTemplateError {
+    MissingPlaceholder(&'static str),
+    UnknownPlaceholder(String),
+    UnsupportedPlaceholder(String),
+    UnterminatedPlaceholder,
+}
+
+impl std::fmt::Display for QueryTemplateError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            QueryTemplateError::MissingPlaceholder(name) => {
+                write!(
+                    formatter,
+                    "Query template is missing the `{{{name}}}` placeholder"
+                )
+            }
+            QueryTemplateError::UnknownPlaceholder(name) => {
+                write!(
+                    formatter,
+                    "Query template has an unknown `{{{name}}}` placeholder"
+                )
+            }
+            QueryTemplateError::UnsupportedPlaceholder(name) => {
+                write!(
+                    formatter,
+                    "Query template has a `{{{name}}}` placeholder that its query doesn't fill in"
+                )
+            }
+            QueryTemplateError::UnterminatedPlaceholder => {
+                write!(formatter, "Query template has an unterminated placeholder")
+            }
+        }
+    }
+}
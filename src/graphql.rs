@@ -0,0 +1,471 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! GraphQL representation of the campaigns' configuration.
+//!
+//! The enums of a [`Campaign`] carry data, so they can't be GraphQL enums. Instead, each one is
+//! an output object with a nullable field for each variant, of which only the field of its
+//! variant is set, and an input object with the same fields, of which exactly one must be set.
+//! Variants without data are represented by `true`, and variants with a single value by that
+//! value.
+//!
+//! Inputs that can only be checked with the application's configured [`Network`], like the
+//! addresses of [`AirDropIdInput`]s, are converted by the resolvers that read it.
+
+use linera_sdk::base::{Amount, ApplicationId};
+
+use crate::{
+    ethereum::{self, EthereumAddress, EthereumHash, Uint256},
+    network::{Network, NetworkSnapshot},
+    ActivityRule, AirDropId, AmountPolicy, Campaign, CampaignId, ClaimWindow, Criterion,
+    Eligibility, EligibilityRule, HoldingRule, LineraIdentity, VestingSchedule,
+};
+
+/// The configuration of a new airdrop campaign.
+#[derive(Clone, Debug, async_graphql::InputObject)]
+pub struct CampaignInput {
+    pub eligibility: EligibilityInput,
+    pub amount: AmountPolicyInput,
+    pub window: ClaimWindow,
+    pub vesting: Option<VestingSchedule>,
+}
+
+impl From<CampaignInput> for Campaign {
+    fn from(input: CampaignInput) -> Self {
+        Campaign {
+            eligibility: input.eligibility.into(),
+            amount: input.amount.into(),
+            window: input.window,
+            vesting: input.vesting,
+        }
+    }
+}
+
+/// The criteria an Ethereum address must meet to be eligible for a campaign's airdrop.
+#[derive(Clone, Debug, async_graphql::InputObject)]
+pub struct EligibilityInput {
+    /// The block up to which the address's balances and activity are read, on the network
+    /// configured in the application's parameters.
+    pub snapshot_block: u64,
+    /// The rule that the address must satisfy.
+    pub rule: EligibilityRuleInput,
+}
+
+impl From<EligibilityInput> for Eligibility {
+    fn from(input: EligibilityInput) -> Self {
+        Eligibility {
+            snapshot_block: input.snapshot_block,
+            rule: input.rule.into(),
+        }
+    }
+}
+
+#[async_graphql::Object]
+impl EligibilityRule {
+    /// A single criterion.
+    async fn criterion(&self) -> Option<&Criterion> {
+        match self {
+            EligibilityRule::Criterion(criterion) => Some(criterion),
+            _ => None,
+        }
+    }
+
+    /// Satisfied if all of the rules are satisfied, or if there are no rules.
+    async fn all(&self) -> Option<&[EligibilityRule]> {
+        match self {
+            EligibilityRule::All(rules) => Some(rules),
+            _ => None,
+        }
+    }
+
+    /// Satisfied if any of the rules is satisfied, but not if there are no rules.
+    async fn any(&self) -> Option<&[EligibilityRule]> {
+        match self {
+            EligibilityRule::Any(rules) => Some(rules),
+            _ => None,
+        }
+    }
+
+    /// Satisfied if the rule isn't satisfied.
+    async fn not(&self) -> Option<&EligibilityRule> {
+        match self {
+            EligibilityRule::Not(rule) => Some(rule),
+            _ => None,
+        }
+    }
+}
+
+/// A boolean expression combining eligibility criteria.
+#[derive(Clone, Debug, async_graphql::OneofObject)]
+pub enum EligibilityRuleInput {
+    /// A single criterion.
+    Criterion(CriterionInput),
+    /// Satisfied if all of the rules are satisfied, or if there are no rules.
+    All(Vec<EligibilityRuleInput>),
+    /// Satisfied if any of the rules is satisfied, but not if there are no rules.
+    Any(Vec<EligibilityRuleInput>),
+    /// Satisfied if the rule isn't satisfied.
+    Not(Box<EligibilityRuleInput>),
+}
+
+impl From<EligibilityRuleInput> for EligibilityRule {
+    fn from(input: EligibilityRuleInput) -> Self {
+        let convert_all = |rules: Vec<EligibilityRuleInput>| rules.into_iter().map(Self::from);
+
+        match input {
+            EligibilityRuleInput::Criterion(criterion) => {
+                EligibilityRule::Criterion(criterion.into())
+            }
+            EligibilityRuleInput::All(rules) => EligibilityRule::All(convert_all(rules).collect()),
+            EligibilityRuleInput::Any(rules) => EligibilityRule::Any(convert_all(rules).collect()),
+            EligibilityRuleInput::Not(rule) => EligibilityRule::Not(Box::new((*rule).into())),
+        }
+    }
+}
+
+#[async_graphql::Object]
+impl Criterion {
+    /// The native token balance, held according to a holding rule, must be at least a minimum.
+    async fn balance(&self) -> Option<BalanceCriterion> {
+        match self {
+            Criterion::Balance { minimum, holding } => Some(BalanceCriterion {
+                minimum: (*minimum).into(),
+                holding: *holding,
+            }),
+            _ => None,
+        }
+    }
+
+    /// The balance of an ERC-20 token must be at least a minimum.
+    async fn token_balance(&self) -> Option<TokenBalanceCriterion> {
+        match self {
+            Criterion::TokenBalance { token, minimum } => Some(TokenBalanceCriterion {
+                token: (*token).into(),
+                minimum: (*minimum).into(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// The sum of the native token balances on several networks must be at least a minimum.
+    async fn combined_balance(&self) -> Option<CombinedBalanceCriterion> {
+        match self {
+            Criterion::CombinedBalance { minimum, snapshots } => Some(CombinedBalanceCriterion {
+                minimum: (*minimum).into(),
+                snapshots: snapshots.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// An on-chain activity rule must be met.
+    async fn activity(&self) -> Option<&ActivityRule> {
+        match self {
+            Criterion::Activity(rule) => Some(rule),
+            _ => None,
+        }
+    }
+
+    /// The address must be in the application's denylist.
+    async fn denylisted(&self) -> Option<bool> {
+        matches!(self, Criterion::Denylisted).then_some(true)
+    }
+
+    /// The address must be in the Merkle allowlist with this root.
+    async fn allowlisted(&self) -> Option<EthereumHash> {
+        match self {
+            Criterion::Allowlisted { root } => Some((*root).into()),
+            _ => None,
+        }
+    }
+
+    /// The claimer must be one of these Linera identities.
+    async fn linera_member(&self) -> Option<Vec<LineraIdentity>> {
+        match self {
+            Criterion::LineraMember { members } => Some(members.iter().copied().collect()),
+            _ => None,
+        }
+    }
+
+    /// The claimer must be a Linera user with at least a minimum balance of a fungible token.
+    async fn linera_token_balance(&self) -> Option<LineraTokenBalanceCriterion> {
+        match self {
+            Criterion::LineraTokenBalance { token, minimum } => Some(LineraTokenBalanceCriterion {
+                token: token.forget_abi(),
+                minimum: *minimum,
+            }),
+            _ => None,
+        }
+    }
+
+    /// This application must report the claimer as a member.
+    async fn linera_application_member(&self) -> Option<ApplicationId> {
+        match self {
+            Criterion::LineraApplicationMember { application } => Some(application.forget_abi()),
+            _ => None,
+        }
+    }
+}
+
+/// A single criterion on an address's balances or activity, or on a Linera identity.
+#[derive(Clone, Debug, async_graphql::OneofObject)]
+pub enum CriterionInput {
+    /// The native token balance, held according to a holding rule, must be at least a minimum.
+    Balance(BalanceCriterionInput),
+    /// The balance of an ERC-20 token must be at least a minimum.
+    TokenBalance(TokenBalanceCriterion),
+    /// The sum of the native token balances on several networks must be at least a minimum.
+    CombinedBalance(CombinedBalanceCriterion),
+    /// An on-chain activity rule must be met.
+    Activity(ActivityRuleInput),
+    /// The address must be in the application's denylist. The value is ignored.
+    Denylisted(bool),
+    /// The address must be in the Merkle allowlist with this root.
+    Allowlisted(EthereumHash),
+    /// The claimer must be one of these Linera identities.
+    LineraMember(Vec<LineraIdentity>),
+    /// The claimer must be a Linera user with at least a minimum balance of a fungible token.
+    LineraTokenBalance(LineraTokenBalanceCriterion),
+    /// This application must report the claimer as a member.
+    LineraApplicationMember(ApplicationId),
+}
+
+impl From<CriterionInput> for Criterion {
+    fn from(input: CriterionInput) -> Self {
+        match input {
+            CriterionInput::Balance(BalanceCriterionInput { minimum, holding }) => {
+                Criterion::Balance {
+                    minimum: minimum.into(),
+                    holding: holding.map(HoldingRule::from).unwrap_or_default(),
+                }
+            }
+            CriterionInput::TokenBalance(TokenBalanceCriterion { token, minimum }) => {
+                Criterion::TokenBalance {
+                    token: token.into(),
+                    minimum: minimum.into(),
+                }
+            }
+            CriterionInput::CombinedBalance(CombinedBalanceCriterion { minimum, snapshots }) => {
+                Criterion::CombinedBalance {
+                    minimum: minimum.into(),
+                    snapshots,
+                }
+            }
+            CriterionInput::Activity(rule) => Criterion::Activity(rule.into()),
+            CriterionInput::Denylisted(_) => Criterion::Denylisted,
+            CriterionInput::Allowlisted(root) => Criterion::Allowlisted { root: root.into() },
+            CriterionInput::LineraMember(members) => Criterion::LineraMember {
+                members: members.into_iter().collect(),
+            },
+            CriterionInput::LineraTokenBalance(LineraTokenBalanceCriterion { token, minimum }) => {
+                Criterion::LineraTokenBalance {
+                    token: token.with_abi(),
+                    minimum,
+                }
+            }
+            CriterionInput::LineraApplicationMember(application) => {
+                Criterion::LineraApplicationMember {
+                    application: application.with_abi(),
+                }
+            }
+        }
+    }
+}
+
+/// A minimum native token balance, held according to a holding rule.
+#[derive(Clone, Debug, async_graphql::SimpleObject)]
+pub struct BalanceCriterion {
+    pub minimum: Uint256,
+    pub holding: HoldingRule,
+}
+
+/// A minimum native token balance, held according to a holding rule, which is the balance at the
+/// snapshot block if it's omitted.
+#[derive(Clone, Debug, async_graphql::InputObject)]
+pub struct BalanceCriterionInput {
+    pub minimum: Uint256,
+    pub holding: Option<HoldingRuleInput>,
+}
+
+/// A minimum balance of an ERC-20 token contract.
+#[derive(Clone, Debug, async_graphql::InputObject, async_graphql::SimpleObject)]
+#[graphql(input_name = "TokenBalanceCriterionInput")]
+pub struct TokenBalanceCriterion {
+    pub token: EthereumAddress,
+    pub minimum: Uint256,
+}
+
+/// A minimum sum of the native token balances on several networks, each at its own snapshot
+/// block.
+#[derive(Clone, Debug, async_graphql::InputObject, async_graphql::SimpleObject)]
+#[graphql(input_name = "CombinedBalanceCriterionInput")]
+pub struct CombinedBalanceCriterion {
+    pub minimum: Uint256,
+    pub snapshots: Vec<NetworkSnapshot>,
+}
+
+/// A minimum balance of a Linera fungible token on the application's creator chain.
+#[derive(Clone, Debug, async_graphql::InputObject, async_graphql::SimpleObject)]
+#[graphql(input_name = "LineraTokenBalanceCriterionInput")]
+pub struct LineraTokenBalanceCriterion {
+    pub token: ApplicationId,
+    pub minimum: Amount,
+}
+
+#[async_graphql::Object]
+impl HoldingRule {
+    /// The balance at the snapshot block must meet the minimum.
+    async fn at_snapshot(&self) -> Option<bool> {
+        matches!(self, HoldingRule::AtSnapshot).then_some(true)
+    }
+
+    /// The balance must meet the minimum at every block from this start block to the snapshot
+    /// block.
+    async fn continuous(&self) -> Option<u64> {
+        match self {
+            HoldingRule::Continuous { start_block } => Some(*start_block),
+            _ => None,
+        }
+    }
+
+    /// The time-weighted average balance from this start block to the snapshot block must meet
+    /// the minimum.
+    async fn time_weighted_average(&self) -> Option<u64> {
+        match self {
+            HoldingRule::TimeWeightedAverage { start_block } => Some(*start_block),
+            _ => None,
+        }
+    }
+}
+
+/// How a balance must be held for an address to be eligible.
+#[derive(Clone, Copy, Debug, async_graphql::OneofObject)]
+pub enum HoldingRuleInput {
+    /// The balance at the snapshot block must meet the minimum. The value is ignored.
+    AtSnapshot(bool),
+    /// The balance must meet the minimum at every block from this start block to the snapshot
+    /// block.
+    Continuous(u64),
+    /// The time-weighted average balance from this start block to the snapshot block must meet
+    /// the minimum.
+    TimeWeightedAverage(u64),
+}
+
+impl From<HoldingRuleInput> for HoldingRule {
+    fn from(input: HoldingRuleInput) -> Self {
+        match input {
+            HoldingRuleInput::AtSnapshot(_) => HoldingRule::AtSnapshot,
+            HoldingRuleInput::Continuous(start_block) => HoldingRule::Continuous { start_block },
+            HoldingRuleInput::TimeWeightedAverage(start_block) => {
+                HoldingRule::TimeWeightedAverage { start_block }
+            }
+        }
+    }
+}
+
+#[async_graphql::Object]
+impl ActivityRule {
+    /// The address must have sent at least this number of transactions.
+    async fn transaction_count(&self) -> Option<u64> {
+        match self {
+            ActivityRule::TransactionCount { minimum } => Some(*minimum),
+            _ => None,
+        }
+    }
+
+    /// The address must have sent at least one transaction to this contract.
+    async fn contract_interaction(&self) -> Option<EthereumAddress> {
+        match self {
+            ActivityRule::ContractInteraction { contract } => Some((*contract).into()),
+            _ => None,
+        }
+    }
+
+    /// The address's first transaction must be before this block.
+    async fn first_seen_before(&self) -> Option<u64> {
+        match self {
+            ActivityRule::FirstSeenBefore { cutoff_block } => Some(*cutoff_block),
+            _ => None,
+        }
+    }
+}
+
+/// A rule on an address's on-chain activity up to the snapshot block.
+#[derive(Clone, Copy, Debug, async_graphql::OneofObject)]
+pub enum ActivityRuleInput {
+    /// The address must have sent at least this number of transactions.
+    TransactionCount(u64),
+    /// The address must have sent at least one transaction to this contract.
+    ContractInteraction(EthereumAddress),
+    /// The address's first transaction must be before this block.
+    FirstSeenBefore(u64),
+}
+
+impl From<ActivityRuleInput> for ActivityRule {
+    fn from(input: ActivityRuleInput) -> Self {
+        match input {
+            ActivityRuleInput::TransactionCount(minimum) => {
+                ActivityRule::TransactionCount { minimum }
+            }
+            ActivityRuleInput::ContractInteraction(contract) => ActivityRule::ContractInteraction {
+                contract: contract.into(),
+            },
+            ActivityRuleInput::FirstSeenBefore(cutoff_block) => {
+                ActivityRule::FirstSeenBefore { cutoff_block }
+            }
+        }
+    }
+}
+
+#[async_graphql::Object]
+impl AmountPolicy {
+    /// Every claimer receives this amount.
+    async fn fixed(&self) -> Option<Amount> {
+        self.amount()
+    }
+
+    /// Each claimer receives the amount set for their address in the campaign's allowlist.
+    async fn allowlisted(&self) -> Option<bool> {
+        matches!(self, AmountPolicy::Allowlisted).then_some(true)
+    }
+}
+
+/// How much is airdropped for each approved claim.
+#[derive(Clone, Copy, Debug, async_graphql::OneofObject)]
+pub enum AmountPolicyInput {
+    /// Every claimer receives this amount.
+    Fixed(Amount),
+    /// Each claimer receives the amount set for their address in the campaign's allowlist. The
+    /// value is ignored.
+    Allowlisted(bool),
+}
+
+impl From<AmountPolicyInput> for AmountPolicy {
+    fn from(input: AmountPolicyInput) -> Self {
+        match input {
+            AmountPolicyInput::Fixed(amount) => AmountPolicy::Fixed(amount),
+            AmountPolicyInput::Allowlisted(_) => AmountPolicy::Allowlisted,
+        }
+    }
+}
+
+/// The information necessary to identify an airdrop.
+#[derive(Clone, Debug, async_graphql::InputObject)]
+pub struct AirDropIdInput {
+    /// The campaign the airdrop belongs to.
+    pub campaign: CampaignId,
+    /// The Ethereum address that claims the airdrop, with the EIP-55 checksum or the EIP-1191
+    /// checksum of the configured network, as displayed by the `AirDropId` object.
+    pub address: String,
+}
+
+impl AirDropIdInput {
+    /// Converts the input into an [`AirDropId`], accepting addresses with the checksum of the
+    /// configured `network`.
+    pub fn into_airdrop_id(self, network: &Network) -> async_graphql::Result<AirDropId> {
+        let address = ethereum::parse_address(&self.address, Some(network.chain_id()))
+            .map_err(async_graphql::Error::new)?;
+
+        Ok(AirDropId::new(self.campaign, address))
+    }
+}
@@ -1,27 +1,44 @@
 // Copyright (c) Zefchain Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod allowlist;
+pub mod balance_proof;
+pub mod ethereum;
+pub mod gateway;
+pub mod graphql;
+pub mod membership;
+pub mod network;
 pub(crate) mod signature_payload;
+pub mod signing;
 #[cfg(feature = "test")]
 pub mod test_utils;
+pub mod validation;
 
-use std::str::FromStr;
+use std::collections::BTreeSet;
 
-use alloy_primitives::{Address, Signature, SignatureError, U256};
-use alloy_sol_types::SolStruct;
-use indexmap::IndexMap;
+use alloy_primitives::{Address, SignatureError, B256, U256};
 use linera_sdk::{
     abis::fungible::{Account, FungibleTokenAbi},
-    base::{ApplicationId, ContractAbi, ServiceAbi},
+    base::{
+        AccountOwner, Amount, ApplicationId, ChainId, ContractAbi, Owner, ServiceAbi, TimeDelta,
+        Timestamp,
+    },
 };
 use serde::{Deserialize, Serialize};
 
-use self::signature_payload::AIRDROP_CLAIM_DOMAIN;
+use self::{
+    allowlist::AllowlistProof,
+    balance_proof::BalanceProof,
+    ethereum::{EthereumAddress, EthereumSignature, Uint256},
+    gateway::GatewayConfiguration,
+    membership::MembershipAbi,
+    network::{Network, NetworkSnapshot},
+};
 
 pub struct ApplicationAbi;
 
 impl ContractAbi for ApplicationAbi {
-    type Operation = AirDropClaim;
+    type Operation = Operation;
     type Response = ();
 }
 
@@ -34,160 +51,614 @@ impl ServiceAbi for ApplicationAbi {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Parameters {
     pub token_id: ApplicationId<FungibleTokenAbi>,
+    pub admin: Owner,
+    /// The network whose balances and activity are read by the campaigns' rules, unless a rule
+    /// specifies its own networks. Its chain ID is also used to checksum the displayed addresses.
+    pub network: Network,
+    /// The BCS serialized Proof-of-SQL commitments to the Space-and-Time tables, used to verify
+    /// claims that carry a [`BalanceProof`] instead of relying on the Gateway.
+    pub proof_of_sql_commitments: Option<Vec<u8>>,
+    /// The Space-and-Time Gateway used to check the eligibility of claims without a
+    /// [`BalanceProof`].
+    pub gateway: GatewayConfiguration,
+}
+
+/// The identifier of an airdrop campaign.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct CampaignId(pub u32);
+
+async_graphql::scalar!(CampaignId);
+
+impl std::fmt::Display for CampaignId {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+/// The configuration of an airdrop campaign.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, async_graphql::SimpleObject)]
+pub struct Campaign {
+    pub eligibility: Eligibility,
+    pub amount: AmountPolicy,
+    pub window: ClaimWindow,
+    pub vesting: Option<VestingSchedule>,
+}
+
+/// The criteria an Ethereum address must meet to be eligible for a campaign's airdrop.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, async_graphql::SimpleObject)]
+pub struct Eligibility {
+    /// The block up to which the address's balances and activity are read, on the network
+    /// configured in the [`Parameters`].
     pub snapshot_block: u64,
-    pub minimum_balance: U256,
+    /// The rule that the address must satisfy.
+    pub rule: EligibilityRule,
 }
 
-/// The information necessary to identify an airdrop.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub struct AirDropId {
-    external_address: Address,
+/// A boolean expression combining eligibility [`Criterion`]s.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum EligibilityRule {
+    /// A single criterion.
+    Criterion(Criterion),
+    /// Satisfied if all of the rules are satisfied, or if there are no rules.
+    All(Vec<EligibilityRule>),
+    /// Satisfied if any of the rules is satisfied, but not if there are no rules.
+    Any(Vec<EligibilityRule>),
+    /// Satisfied if the rule isn't satisfied.
+    Not(Box<EligibilityRule>),
 }
 
-impl From<Address> for AirDropId {
-    fn from(external_address: Address) -> Self {
-        AirDropId { external_address }
+impl EligibilityRule {
+    /// Creates a rule that requires a minimum balance at the snapshot block.
+    pub fn minimum_balance(minimum: U256) -> Self {
+        EligibilityRule::Criterion(Criterion::Balance {
+            minimum,
+            holding: HoldingRule::AtSnapshot,
+        })
     }
-}
 
-#[async_graphql::Scalar]
-impl async_graphql::ScalarType for AirDropId {
-    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
-        match value {
-            async_graphql::Value::List(bytes_list) => {
-                let parse_bytes_result = bytes_list
-                    .into_iter()
-                    .map(<u8 as async_graphql::ScalarType>::parse)
-                    .collect::<Result<Vec<u8>, _>>();
+    /// Returns `true` if any of the rule's criteria satisfies the `predicate`.
+    pub fn any_criterion(&self, predicate: &impl Fn(&Criterion) -> bool) -> bool {
+        match self {
+            EligibilityRule::Criterion(criterion) => predicate(criterion),
+            EligibilityRule::All(rules) | EligibilityRule::Any(rules) => {
+                rules.iter().any(|rule| rule.any_criterion(predicate))
+            }
+            EligibilityRule::Not(rule) => rule.any_criterion(predicate),
+        }
+    }
 
-                let prepare_bytes_result = parse_bytes_result.and_then(|bytes| {
-                    <[u8; 20]>::try_from(bytes).map_err(|_| {
-                        async_graphql::InputValueError::custom(
-                            "Ethereum address needs exactly 20 bytes",
-                        )
-                    })
-                });
+    /// Returns `true` if the rule has criteria on Linera identities, which are checked by the
+    /// contract for [`Operation::LineraClaim`]s.
+    pub fn has_linera_criteria(&self) -> bool {
+        self.any_criterion(&Criterion::is_linera)
+    }
+}
 
-                let external_address = match prepare_bytes_result {
-                    Ok(bytes) => Address::from(bytes),
-                    Err(error) => return Err(error.propagate()),
-                };
+impl std::fmt::Display for EligibilityRule {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (rules, separator) = match self {
+            EligibilityRule::Criterion(criterion) => {
+                return std::fmt::Display::fmt(criterion, formatter)
+            }
+            EligibilityRule::Not(rule) => return write!(formatter, "NOT {rule}"),
+            EligibilityRule::All(rules) if rules.is_empty() => return formatter.write_str("true"),
+            EligibilityRule::Any(rules) if rules.is_empty() => return formatter.write_str("false"),
+            EligibilityRule::All(rules) => (rules, " AND "),
+            EligibilityRule::Any(rules) => (rules, " OR "),
+        };
 
-                Ok(AirDropId { external_address })
+        formatter.write_str("(")?;
+        for (index, rule) in rules.iter().enumerate() {
+            if index > 0 {
+                formatter.write_str(separator)?;
             }
-            async_graphql::Value::String(address_string) => {
-                let hex_string = address_string.strip_prefix("0x").unwrap_or(&address_string);
-                let external_address = Address::from_str(hex_string)?;
+            std::fmt::Display::fmt(rule, formatter)?;
+        }
+        formatter.write_str(")")
+    }
+}
 
-                Ok(AirDropId { external_address })
+/// A single criterion on an address's balances or activity up to the
+/// [`Eligibility::snapshot_block`], on the network configured in the [`Parameters`] unless the
+/// criterion specifies its own networks.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Criterion {
+    /// The native token balance, held according to the `holding` rule, must be at least
+    /// `minimum`.
+    Balance { minimum: U256, holding: HoldingRule },
+    /// The balance of an ERC-20 `token` must be at least `minimum`.
+    TokenBalance { token: Address, minimum: U256 },
+    /// The sum of the native token balances on several networks, each at its own snapshot block,
+    /// must be at least `minimum`.
+    CombinedBalance {
+        minimum: U256,
+        snapshots: Vec<NetworkSnapshot>,
+    },
+    /// An on-chain activity rule must be met.
+    Activity(ActivityRule),
+    /// The address must be in the application's denylist.
+    Denylisted,
+    /// The address must be in the Merkle allowlist with the `root`, which is proven by an
+    /// [`AllowlistProof`] in the claim instead of being queried from Space-and-Time.
+    Allowlisted { root: B256 },
+    /// The claimer must be one of the Linera `members`, authenticated by an
+    /// [`Operation::LineraClaim`] instead of an Ethereum signature.
+    LineraMember { members: BTreeSet<LineraIdentity> },
+    /// The claimer must be a Linera user with at least `minimum` tokens of the fungible `token`
+    /// on the application's creator chain, read when the [`Operation::LineraClaim`] is handled.
+    LineraTokenBalance {
+        token: ApplicationId<FungibleTokenAbi>,
+        minimum: Amount,
+    },
+    /// The `application` on the application's creator chain must report the claimer as a member
+    /// when the [`Operation::LineraClaim`] is handled.
+    LineraApplicationMember {
+        application: ApplicationId<MembershipAbi>,
+    },
+}
+
+impl Criterion {
+    /// Returns `true` if the criterion is on Linera identities instead of Ethereum addresses.
+    pub fn is_linera(&self) -> bool {
+        matches!(
+            self,
+            Criterion::LineraMember { .. }
+                | Criterion::LineraTokenBalance { .. }
+                | Criterion::LineraApplicationMember { .. }
+        )
+    }
+}
+
+impl std::fmt::Display for Criterion {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Criterion::Balance {
+                minimum,
+                holding: HoldingRule::AtSnapshot,
+            } => write!(formatter, "balance >= {minimum}"),
+            Criterion::Balance {
+                minimum,
+                holding: HoldingRule::Continuous { start_block },
+            } => write!(formatter, "held_balance({start_block}) >= {minimum}"),
+            Criterion::Balance {
+                minimum,
+                holding: HoldingRule::TimeWeightedAverage { start_block },
+            } => write!(formatter, "average_balance({start_block}) >= {minimum}"),
+            Criterion::TokenBalance { token, minimum } => {
+                write!(formatter, "erc20({token}) >= {minimum}")
+            }
+            Criterion::CombinedBalance { minimum, snapshots } => {
+                formatter.write_str("combined_balance(")?;
+                for (index, snapshot) in snapshots.iter().enumerate() {
+                    if index > 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    write!(formatter, "{snapshot}")?;
+                }
+                write!(formatter, ") >= {minimum}")
+            }
+            Criterion::Activity(ActivityRule::TransactionCount { minimum }) => {
+                write!(formatter, "tx_count >= {minimum}")
+            }
+            Criterion::Activity(ActivityRule::ContractInteraction { contract }) => {
+                write!(formatter, "interacted({contract})")
+            }
+            Criterion::Activity(ActivityRule::FirstSeenBefore { cutoff_block }) => {
+                write!(formatter, "first_seen < {cutoff_block}")
+            }
+            Criterion::Denylisted => formatter.write_str("denylisted"),
+            Criterion::Allowlisted { root } => write!(formatter, "allowlisted({root})"),
+            Criterion::LineraMember { members } => {
+                write!(formatter, "linera_member({} members)", members.len())
+            }
+            Criterion::LineraTokenBalance { token, minimum } => {
+                write!(
+                    formatter,
+                    "linera_balance({}) >= {minimum}",
+                    token.forget_abi()
+                )
+            }
+            Criterion::LineraApplicationMember { application } => {
+                write!(
+                    formatter,
+                    "linera_application_member({})",
+                    application.forget_abi()
+                )
             }
-            _ => Err(async_graphql::InputValueError::expected_type(value)),
         }
     }
+}
+
+/// The outcome of checking an address's eligibility for a campaign, together with the evidence
+/// it's based on.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, async_graphql::SimpleObject)]
+#[graphql(complex)]
+pub struct EligibilityEvidence {
+    /// Whether the address is eligible.
+    pub eligible: bool,
+    /// The native token balance observed for the address, if the rule reads one.
+    #[graphql(skip)]
+    pub balance: Option<U256>,
+    /// The snapshot block the address's balances and activity were read at.
+    pub block: u64,
+    /// The part of the campaign's rule that the address satisfied, if it's eligible.
+    pub matched_rule: Option<String>,
+    /// The amount airdropped to the address, which is zero if it isn't eligible.
+    pub amount: Amount,
+}
 
-    fn to_value(&self) -> async_graphql::Value {
-        self.external_address
-            .to_checksum(Some(ETHEREUM_MAINNET_CHAIN_ID))
-            .into()
+#[async_graphql::ComplexObject]
+impl EligibilityEvidence {
+    /// The native token balance observed for the address, if the rule reads one.
+    #[graphql(name = "balance")]
+    async fn graphql_balance(&self) -> Option<Uint256> {
+        self.balance.map(Uint256)
     }
 }
 
-/// An airdrop claim.
-#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
-pub struct AirDropClaim {
-    pub signature: Signature,
-    pub destination: Account,
-    pub api_token: String,
+/// How a [`Criterion::Balance`] must be held for an address to be eligible.
+///
+/// Holding periods end at the [`Eligibility::snapshot_block`], and make it harder to game the
+/// snapshot with tokens borrowed for a single block.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum HoldingRule {
+    /// The balance at the snapshot block must meet the minimum.
+    #[default]
+    AtSnapshot,
+    /// The balance must meet the minimum at every block from `start_block` to the snapshot
+    /// block.
+    Continuous { start_block: u64 },
+    /// The time-weighted average balance from `start_block` to the snapshot block must meet the
+    /// minimum.
+    TimeWeightedAverage { start_block: u64 },
 }
 
-impl AirDropClaim {
-    /// Returns the signer's Ethereum [`Address`] for this [`AirDropClaim`].
-    pub fn signer_address(
-        &self,
-        application_id: ApplicationId<ApplicationAbi>,
-    ) -> Result<Address, SignatureError> {
-        let payload = signature_payload::AirDropClaim::new(application_id, &self.destination);
+/// A rule on an address's on-chain activity up to the [`Eligibility::snapshot_block`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ActivityRule {
+    /// The address must have sent at least `minimum` transactions.
+    TransactionCount { minimum: u64 },
+    /// The address must have sent at least one transaction to the `contract`.
+    ContractInteraction { contract: Address },
+    /// The address's first transaction must be before the `cutoff_block`.
+    FirstSeenBefore { cutoff_block: u64 },
+}
 
-        let hash = payload.eip712_signing_hash(&AIRDROP_CLAIM_DOMAIN);
+/// How much is airdropped for each approved claim.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum AmountPolicy {
+    /// Every claimer receives the same amount.
+    Fixed(Amount),
+    /// Each claimer receives the amount set for their address in the campaign's
+    /// [`Criterion::Allowlisted`] Merkle allowlist.
+    Allowlisted,
+}
 
-        self.signature.recover_address_from_prehash(&hash)
+impl AmountPolicy {
+    /// Returns the [`Amount`] to be airdropped for one claim, or `None` if it's set for each
+    /// address by an allowlist.
+    pub fn amount(&self) -> Option<Amount> {
+        match self {
+            AmountPolicy::Fixed(amount) => Some(*amount),
+            AmountPolicy::Allowlisted => None,
+        }
     }
 }
 
-#[async_graphql::Scalar]
-impl async_graphql::ScalarType for AirDropClaim {
-    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
-        let async_graphql::Value::Object(mut fields) = value else {
-            return Err(async_graphql::InputValueError::expected_type(value));
-        };
+/// The period in which a campaign accepts claims.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    PartialEq,
+    Serialize,
+    async_graphql::InputObject,
+    async_graphql::SimpleObject,
+)]
+#[graphql(input_name = "ClaimWindowInput")]
+pub struct ClaimWindow {
+    pub start: Timestamp,
+    pub end: Timestamp,
+}
+
+impl ClaimWindow {
+    /// Returns `true` if claims made at the `timestamp` are accepted.
+    pub fn contains(&self, timestamp: Timestamp) -> bool {
+        self.start <= timestamp && timestamp < self.end
+    }
+}
+
+/// A linear vesting schedule for the airdropped tokens.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    PartialEq,
+    Serialize,
+    async_graphql::InputObject,
+    async_graphql::SimpleObject,
+)]
+#[graphql(input_name = "VestingScheduleInput")]
+pub struct VestingSchedule {
+    /// The time after an airdrop is approved before any of its tokens vest.
+    pub cliff: TimeDelta,
+    /// The time after an airdrop is approved for all of its tokens to vest.
+    pub duration: TimeDelta,
+    /// The granularity in which tokens vest.
+    pub release_interval: TimeDelta,
+}
+
+impl VestingSchedule {
+    /// Returns how much of a `total` [`Amount`] has vested at `now`, for a vesting position
+    /// created at `start`.
+    pub fn vested_amount(&self, total: Amount, start: Timestamp, now: Timestamp) -> Amount {
+        let elapsed = now.delta_since(start);
+
+        if elapsed < self.cliff {
+            return Amount::ZERO;
+        }
+        if elapsed >= self.duration {
+            return total;
+        }
+
+        let interval = self.release_interval.as_micros().max(1);
+        let vested_time = elapsed.as_micros() / interval * interval;
+        let vested_attos = U256::from(u128::from(total)) * U256::from(vested_time)
+            / U256::from(self.duration.as_micros());
 
-        if fields.len() != 2 {
-            return Err(async_graphql::InputValueError::custom(
-                "`AirDropClaim` object must have exactly three fields: \
-                `signature`, `destination` and `apiToken`",
-            ));
+        Amount::from_attos(vested_attos.to::<u128>())
+    }
+}
+
+/// The operations that can be executed by the application.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Operation {
+    /// Creates a new campaign. Only allowed for the application's admin.
+    CreateCampaign(Campaign),
+    /// Claims an airdrop.
+    Claim(AirDropClaim),
+    /// Claims multiple airdrops, handling each claim independently so that invalid claims
+    /// don't prevent the others from being paid.
+    ClaimBatch(Vec<AirDropClaim>),
+    /// Claims an airdrop from a campaign with a rule on Linera identities, as the authenticated
+    /// signer of the operation or as the chain it's submitted from.
+    LineraClaim {
+        campaign: CampaignId,
+        destination: Account,
+    },
+    /// Transfers tokens from the authenticated signer's account into a campaign's budget.
+    Fund {
+        campaign: CampaignId,
+        amount: Amount,
+    },
+    /// Releases the tokens that have vested so far for an airdrop.
+    Release { id: AirDropId },
+    /// Adds and removes entries from the denylist. Only allowed for the application's admin.
+    UpdateDenylist {
+        add: Vec<DenylistEntry>,
+        remove: Vec<DenylistEntry>,
+    },
+}
+
+/// An entry in the denylist, preventing tokens from being airdropped to or through it.
+///
+/// In GraphQL inputs, exactly one of the fields must be set.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize, async_graphql::OneofObject,
+)]
+#[graphql(name = "DenylistEntryInput")]
+pub enum DenylistEntry {
+    /// An Ethereum address that can't claim airdrops.
+    ExternalAddress(EthereumAddress),
+    /// A chain that can't receive airdropped tokens or relayer fees.
+    Chain(ChainId),
+    /// An account owner that can't receive airdropped tokens or relayer fees.
+    Owner(AccountOwner),
+}
+
+/// In GraphQL outputs, only the field of the entry's variant is set.
+#[async_graphql::Object]
+impl DenylistEntry {
+    /// An Ethereum address that can't claim airdrops.
+    async fn external_address(&self) -> Option<EthereumAddress> {
+        match self {
+            DenylistEntry::ExternalAddress(address) => Some(*address),
+            _ => None,
         }
+    }
 
-        let Some(signature_value) = fields.swap_remove("signature") else {
-            return Err(async_graphql::InputValueError::custom(
-                "`AirDropClaim` object is missing an `signature` field",
-            ));
-        };
+    /// A chain that can't receive airdropped tokens or relayer fees.
+    async fn chain(&self) -> Option<ChainId> {
+        match self {
+            DenylistEntry::Chain(chain_id) => Some(*chain_id),
+            _ => None,
+        }
+    }
 
-        let async_graphql::Value::String(signature_string) = signature_value else {
-            return Err(async_graphql::InputValueError::custom(
-                "`AirDropClaim`'s `signature` is not a string",
-            ));
-        };
+    /// An account owner that can't receive airdropped tokens or relayer fees.
+    async fn owner(&self) -> Option<AccountOwner> {
+        match self {
+            DenylistEntry::Owner(owner) => Some(*owner),
+            _ => None,
+        }
+    }
+}
 
-        let signature = Signature::from_str(&signature_string).map_err(|_| {
-            async_graphql::InputValueError::custom(
-                "`AirDropClaim`'s `signature` is not a valid signature string",
-            )
-        })?;
+/// The information necessary to identify an airdrop.
+///
+/// In GraphQL inputs, it's an [`AirDropIdInput`][graphql::AirDropIdInput].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AirDropId {
+    /// The campaign the airdrop belongs to.
+    #[serde(rename = "campaign")]
+    campaign_id: CampaignId,
+    /// The Ethereum address that claims the airdrop.
+    #[serde(rename = "external_address")]
+    address: EthereumAddress,
+}
 
-        let Some(destination_value) = fields.swap_remove("destination") else {
-            return Err(async_graphql::InputValueError::custom(
-                "`AirDropClaim` object is missing an `destination` field",
-            ));
-        };
+impl AirDropId {
+    /// Creates a new [`AirDropId`] for an `external_address` in a `campaign`.
+    pub fn new(campaign: CampaignId, external_address: Address) -> Self {
+        AirDropId {
+            campaign_id: campaign,
+            address: external_address.into(),
+        }
+    }
 
-        let destination =
-            match <Account as async_graphql::InputType>::parse(Some(destination_value)) {
-                Ok(destination) => destination,
-                Err(error) => return Err(error.propagate()),
-            };
+    /// Returns the [`CampaignId`] of the campaign this airdrop belongs to.
+    pub fn campaign(&self) -> CampaignId {
+        self.campaign_id
+    }
 
-        let Some(api_token_value) = fields.swap_remove("apiToken") else {
-            return Err(async_graphql::InputValueError::custom(
-                "`AirDropClaim` object is missing an `signature` field",
-            ));
-        };
+    /// Returns the Ethereum [`Address`] that claimed this airdrop.
+    pub fn external_address(&self) -> Address {
+        self.address.0
+    }
+}
 
-        let async_graphql::Value::String(api_token) = api_token_value else {
-            return Err(async_graphql::InputValueError::custom(
-                "`AirDropClaim`'s `apiToken` is not a string",
-            ));
-        };
+#[async_graphql::Object]
+impl AirDropId {
+    /// The campaign the airdrop belongs to.
+    #[graphql(name = "campaign")]
+    async fn graphql_campaign(&self) -> CampaignId {
+        self.campaign_id
+    }
 
-        Ok(AirDropClaim {
-            signature,
-            destination,
-            api_token,
-        })
+    /// The Ethereum address that claims the airdrop, with the [EIP-1191] checksum of the
+    /// [`Network`] that the service adds to the GraphQL context.
+    ///
+    /// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+    async fn address(&self, context: &async_graphql::Context<'_>) -> async_graphql::Result<String> {
+        let network = context.data::<Network>()?;
+
+        Ok(self.address.0.to_checksum(Some(network.chain_id())))
+    }
+}
+
+/// A Linera identity that can claim airdrops from campaigns with a rule on Linera identities.
+///
+/// In GraphQL inputs, exactly one of the fields must be set.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    async_graphql::OneofObject,
+)]
+#[graphql(name = "LineraIdentityInput")]
+pub enum LineraIdentity {
+    /// A user, authenticated as the signer of the claim.
+    Owner(Owner),
+    /// A chain, whose owners can claim by submitting the claim from it.
+    Chain(ChainId),
+}
+
+/// In GraphQL outputs, only the field of the identity's variant is set.
+#[async_graphql::Object]
+impl LineraIdentity {
+    /// A user, authenticated as the signer of the claim.
+    async fn owner(&self) -> Option<Owner> {
+        match self {
+            LineraIdentity::Owner(owner) => Some(*owner),
+            _ => None,
+        }
+    }
+
+    /// A chain, whose owners can claim by submitting the claim from it.
+    async fn chain(&self) -> Option<ChainId> {
+        match self {
+            LineraIdentity::Chain(chain_id) => Some(*chain_id),
+            _ => None,
+        }
+    }
+}
+
+impl From<LineraIdentity> for DenylistEntry {
+    fn from(identity: LineraIdentity) -> Self {
+        match identity {
+            LineraIdentity::Owner(owner) => DenylistEntry::Owner(AccountOwner::User(owner)),
+            LineraIdentity::Chain(chain_id) => DenylistEntry::Chain(chain_id),
+        }
     }
+}
 
-    fn to_value(&self) -> async_graphql::Value {
-        let mut fields = IndexMap::new();
+impl std::fmt::Display for LineraIdentity {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LineraIdentity::Owner(owner) => write!(formatter, "owner {owner}"),
+            LineraIdentity::Chain(chain_id) => write!(formatter, "chain {chain_id}"),
+        }
+    }
+}
 
-        let signature_string = hex::encode(self.signature.as_bytes());
-        let signature = async_graphql::ScalarType::to_value(&signature_string);
-        let destination = async_graphql::InputType::to_value(&self.destination);
+/// The information necessary to identify an airdrop claimed by a [`LineraIdentity`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    PartialEq,
+    Serialize,
+    async_graphql::InputObject,
+    async_graphql::SimpleObject,
+)]
+#[graphql(input_name = "LineraAirDropIdInput")]
+pub struct LineraAirDropId {
+    /// The campaign the airdrop belongs to.
+    pub campaign: CampaignId,
+    /// The Linera identity that claims the airdrop.
+    pub claimer: LineraIdentity,
+}
 
-        fields.insert(async_graphql::Name::new("signature"), signature);
-        fields.insert(async_graphql::Name::new("destination"), destination);
+/// An airdrop claim.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, async_graphql::InputObject)]
+pub struct AirDropClaim {
+    /// The campaign to claim the airdrop from.
+    pub campaign: CampaignId,
+    /// The claimer's EIP-712 signature of the claim.
+    pub signature: EthereumSignature,
+    /// The account that receives the airdropped tokens.
+    pub destination: Account,
+    /// The Space-and-Time API token used to check the claimer's eligibility through the
+    /// Gateway.
+    #[graphql(default)]
+    pub api_token: String,
+    /// An optional fee paid from the airdropped tokens to the relayer that submits the claim.
+    pub relayer_fee: Option<Amount>,
+    /// An optional Proof-of-SQL result for the claimer's balance, replacing the Gateway query.
+    pub balance_proof: Option<BalanceProof>,
+    /// A proof that the claimer is in the campaign's allowlist, required by campaigns with a
+    /// [`Criterion::Allowlisted`] rule.
+    pub allowlist_proof: Option<AllowlistProof>,
+}
 
-        async_graphql::Value::Object(fields)
+impl AirDropClaim {
+    /// Returns the signer's Ethereum [`Address`] for this [`AirDropClaim`].
+    pub fn signer_address(
+        &self,
+        application_id: ApplicationId<ApplicationAbi>,
+    ) -> Result<Address, SignatureError> {
+        signing::recover_claim_signer(
+            &self.signature.0,
+            application_id,
+            self.campaign,
+            &self.destination,
+            self.relayer_fee,
+        )
     }
 }
 
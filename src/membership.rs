@@ -0,0 +1,36 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The interface of Linera applications that report which identities are their members, so that
+//! campaigns can check it with a [`Criterion::LineraApplicationMember`].
+//!
+//! [`Criterion::LineraApplicationMember`]: crate::Criterion::LineraApplicationMember
+
+use linera_sdk::base::{ContractAbi, ServiceAbi};
+use serde::{Deserialize, Serialize};
+
+use crate::LineraIdentity;
+
+/// The ABI of applications that report the membership of Linera identities, such as staking
+/// applications.
+///
+/// Applications implement it by handling [`MembershipOperation`]s in their contracts, which the
+/// airdrop application calls without authentication on its creator chain.
+pub struct MembershipAbi;
+
+impl ContractAbi for MembershipAbi {
+    type Operation = MembershipOperation;
+    type Response = bool;
+}
+
+impl ServiceAbi for MembershipAbi {
+    type Query = async_graphql::Request;
+    type QueryResponse = async_graphql::Response;
+}
+
+/// The operations of a [`MembershipAbi`] application called by the airdrop application.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum MembershipOperation {
+    /// Checks if the `identity` is a member, responding with `true` if it is.
+    IsMember { identity: LineraIdentity },
+}
@@ -0,0 +1,142 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The EVM networks indexed by Space-and-Time that eligibility can be checked against.
+
+use std::str::FromStr;
+
+use alloy_primitives::aliases::ChainId;
+use serde::{Deserialize, Serialize};
+
+use crate::ETHEREUM_MAINNET_CHAIN_ID;
+
+/// An EVM network, identified by the Space-and-Time schema with its tables.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Network {
+    #[default]
+    Ethereum,
+    Polygon,
+    Arbitrum,
+    Base,
+    /// Another network indexed by Space-and-Time.
+    Other {
+        schema: String,
+        chain_id: ChainId,
+    },
+}
+
+impl Network {
+    /// Returns the name of the Space-and-Time schema with the network's tables.
+    pub fn schema(&self) -> &str {
+        match self {
+            Network::Ethereum => "ETHEREUM",
+            Network::Polygon => "POLYGON",
+            Network::Arbitrum => "ARBITRUM",
+            Network::Base => "BASE",
+            Network::Other { schema, .. } => schema,
+        }
+    }
+
+    /// Returns the network's [EIP-155] chain ID.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub fn chain_id(&self) -> ChainId {
+        match self {
+            Network::Ethereum => ETHEREUM_MAINNET_CHAIN_ID,
+            Network::Polygon => 137,
+            Network::Arbitrum => 42_161,
+            Network::Base => 8_453,
+            Network::Other { chain_id, .. } => *chain_id,
+        }
+    }
+}
+
+impl std::fmt::Display for Network {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(&self.schema().to_lowercase())
+    }
+}
+
+impl FromStr for Network {
+    type Err = ParseNetworkError;
+
+    /// Parses a [`Network`] from its lowercase name, or from `SCHEMA:CHAIN_ID` for other
+    /// networks.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_lowercase().as_str() {
+            "ethereum" => Ok(Network::Ethereum),
+            "polygon" => Ok(Network::Polygon),
+            "arbitrum" => Ok(Network::Arbitrum),
+            "base" => Ok(Network::Base),
+            _ => {
+                let (schema, chain_id) = string.split_once(':').ok_or(ParseNetworkError)?;
+
+                Ok(Network::Other {
+                    schema: schema.to_owned(),
+                    chain_id: chain_id.parse().map_err(|_| ParseNetworkError)?,
+                })
+            }
+        }
+    }
+}
+
+/// In GraphQL, a [`Network`] is a string in the format parsed by its [`FromStr`] implementation.
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for Network {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let async_graphql::Value::String(network_string) = &value else {
+            return Err(async_graphql::InputValueError::expected_type(value));
+        };
+
+        Ok(network_string.parse()?)
+    }
+
+    fn is_valid(value: &async_graphql::Value) -> bool {
+        matches!(value, async_graphql::Value::String(_))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::String(match self {
+            Network::Other { schema, chain_id } => format!("{schema}:{chain_id}"),
+            network => network.to_string(),
+        })
+    }
+}
+
+/// Error returned when parsing an unknown [`Network`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseNetworkError;
+
+impl std::fmt::Display for ParseNetworkError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(
+            "Unknown network, expected `ethereum`, `polygon`, `arbitrum`, `base` or \
+            `SCHEMA:CHAIN_ID`",
+        )
+    }
+}
+
+impl std::error::Error for ParseNetworkError {}
+
+/// A snapshot block on a specific [`Network`].
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
+    Eq,
+    PartialEq,
+    Serialize,
+    async_graphql::InputObject,
+    async_graphql::SimpleObject,
+)]
+#[graphql(input_name = "NetworkSnapshotInput")]
+pub struct NetworkSnapshot {
+    pub network: Network,
+    pub block: u64,
+}
+
+impl std::fmt::Display for NetworkSnapshot {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "{}@{}", self.network, self.block)
+    }
+}
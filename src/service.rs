@@ -12,16 +12,31 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use airdrop_demo::{AirDropClaim, Parameters};
-use alloy_primitives::U256;
+use airdrop_demo::{
+    allowlist::AllowlistProof,
+    balance_proof::BalanceProof,
+    ethereum::{EthereumAddress, EthereumSignature},
+    gateway::{GatewayConfiguration, QueryArguments},
+    graphql::{AirDropIdInput, CampaignInput},
+    network::Network,
+    ActivityRule, AirDropClaim, Campaign, CampaignId, Criterion, DenylistEntry, Eligibility,
+    EligibilityEvidence, EligibilityRule, HoldingRule, LineraAirDropId, LineraIdentity, Operation,
+    Parameters,
+};
+use alloy_primitives::{Address, U256};
 use async_graphql::{EmptySubscription, Schema};
 use linera_sdk::{
-    abis::fungible, base::WithServiceAbi, bcs, ensure, http, serde_json, Service, ServiceRuntime,
+    abis::fungible,
+    base::{AccountOwner, Amount, WithServiceAbi},
+    bcs, ensure, http, serde_json,
+    views::View,
+    Service, ServiceRuntime,
 };
 
-#[derive(Clone)]
+use self::state::{Application, Budget, ClaimRecord, Deposit, RejectedClaim, VestingPosition};
+
 pub struct ApplicationService {
-    runtime: Arc<Mutex<ServiceRuntime<Self>>>,
+    schema: ApplicationSchema,
 }
 
 linera_sdk::service!(ApplicationService);
@@ -34,131 +49,613 @@ impl Service for ApplicationService {
     type Parameters = Parameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
-        ApplicationService {
-            runtime: Arc::new(Mutex::new(runtime)),
-        }
+        let state = Application::load(runtime.root_view_storage_context())
+            .await
+            .expect("Failed to load state");
+
+        ApplicationService::with_state(state, Arc::new(Mutex::new(runtime)))
     }
 
     async fn handle_query(&self, query: Self::Query) -> Self::QueryResponse {
-        Schema::build(Query(self.clone()), Mutation, EmptySubscription)
-            .finish()
-            .execute(query)
-            .await
+        self.schema.execute(query).await
+    }
+}
+
+/// The GraphQL schema served by the application.
+pub type ApplicationSchema = Schema<Query, Mutation, EmptySubscription>;
+
+impl ApplicationService {
+    /// Creates an [`ApplicationService`] with the loaded `state` and the `runtime` shared with
+    /// the [`Query`] root.
+    ///
+    /// The [`ApplicationSchema`] is built once here and reused by every query, including the
+    /// oracle queries made by the contract, which every validator executes for every claim. The
+    /// configured [`Network`] is added to its data, so that the displayed addresses use its
+    /// checksum.
+    fn with_state(state: Application, runtime: Arc<Mutex<ServiceRuntime<Self>>>) -> Self {
+        let network = runtime
+            .lock()
+            .expect("Panics should abort service, so mutex should never be poisoned")
+            .application_parameters()
+            .network;
+        let query = Query {
+            state: Arc::new(state),
+            runtime,
+        };
+        let schema = Schema::build(query, Mutation, EmptySubscription)
+            .data(network)
+            .finish();
+
+        ApplicationService { schema }
     }
 }
 
 /// Root type that defines all the GraphQL queries available from the service.
-pub struct Query(ApplicationService);
+pub struct Query {
+    state: Arc<Application>,
+    runtime: Arc<Mutex<ServiceRuntime<ApplicationService>>>,
+}
 
 #[async_graphql::Object]
 impl Query {
-    /// Checks if an address is eligible to claim an airdrop.
+    /// Returns the configuration of a campaign, if it exists.
+    async fn campaign(&self, id: CampaignId) -> async_graphql::Result<Option<Campaign>> {
+        Ok(self.state.campaigns.get(&id).await?)
+    }
+
+    /// Returns the amounts funded, paid and remaining in a campaign's budget.
+    async fn budget(&self, campaign: CampaignId) -> async_graphql::Result<Budget> {
+        Ok(self.state.budgets.get(&campaign).await?.unwrap_or_default())
+    }
+
+    /// Returns the total amount deposited by a `funder` across all campaigns.
+    async fn funded_by(&self, funder: AccountOwner) -> async_graphql::Result<Amount> {
+        Ok(self.state.funders.get(&funder).await?.unwrap_or_default())
+    }
+
+    /// Lists all deposits made into the campaigns' budgets, in the order they were recorded.
+    async fn deposits(&self) -> async_graphql::Result<Vec<Deposit>> {
+        let deposits = &self.state.deposits;
+
+        Ok(deposits.read(0..deposits.count()).await?)
+    }
+
+    /// Returns the vesting position of an airdrop, if it has one.
+    async fn vesting_position(
+        &self,
+        context: &async_graphql::Context<'_>,
+        id: AirDropIdInput,
+    ) -> async_graphql::Result<Option<VestingPosition>> {
+        let id = id.into_airdrop_id(context.data::<Network>()?)?;
+
+        Ok(self.state.vesting_positions.get(&id).await?)
+    }
+
+    /// Returns the record of an approved claim, with the evidence of the claimer's eligibility.
+    async fn claim_record(
+        &self,
+        context: &async_graphql::Context<'_>,
+        id: AirDropIdInput,
+    ) -> async_graphql::Result<Option<ClaimRecord>> {
+        let id = id.into_airdrop_id(context.data::<Network>()?)?;
+
+        Ok(self.state.handled_airdrops.get(&id).await?)
+    }
+
+    /// Returns the record of an approved claim by a Linera identity.
+    async fn linera_claim_record(
+        &self,
+        id: LineraAirDropId,
+    ) -> async_graphql::Result<Option<ClaimRecord>> {
+        Ok(self.state.linera_airdrops.get(&id).await?)
+    }
+
+    /// Checks if a Linera `identity` is one of the members of a campaign with a
+    /// [`Criterion::LineraMember`] rule.
+    async fn is_linera_member(
+        &self,
+        campaign: CampaignId,
+        identity: LineraIdentity,
+    ) -> async_graphql::Result<bool> {
+        let Campaign { eligibility, .. } = self
+            .state
+            .campaigns
+            .get(&campaign)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new(format!("Unknown campaign {campaign}")))?;
+
+        let EligibilityRule::Criterion(Criterion::LineraMember { members }) = eligibility.rule
+        else {
+            return Err(async_graphql::Error::new(format!(
+                "Campaign {campaign} doesn't have Linera members"
+            )));
+        };
+
+        Ok(members.contains(&identity))
+    }
+
+    /// Checks if an `entry` is in the denylist.
+    async fn is_denied(&self, entry: DenylistEntry) -> async_graphql::Result<bool> {
+        Ok(self.state.denylist.contains(&entry).await?)
+    }
+
+    /// Lists all claims that were rejected by the creator chain, in the order they were recorded.
+    async fn rejected_claims(&self) -> async_graphql::Result<Vec<RejectedClaim>> {
+        let rejected_claims = &self.state.rejected_claims;
+
+        Ok(rejected_claims.read(0..rejected_claims.count()).await?)
+    }
+
+    /// Checks if an address is eligible to claim an airdrop from a campaign, returning the
+    /// evidence the result is based on.
     async fn check_eligibility(
         &self,
-        address: String,
+        campaign: CampaignId,
+        address: EthereumAddress,
         api_token: String,
-    ) -> async_graphql::Result<bool> {
-        let lowercase_address = address.to_lowercase();
+    ) -> async_graphql::Result<EligibilityEvidence> {
+        let (evidence, _) = self
+            .evaluate_eligibility(campaign, address.0, api_token)
+            .await?;
+
+        Ok(evidence)
+    }
+
+    /// Evaluates a campaign's eligibility rule for an address, reporting the result of each of
+    /// the rule's clauses.
+    async fn eligibility_report(
+        &self,
+        campaign: CampaignId,
+        address: EthereumAddress,
+        api_token: String,
+    ) -> async_graphql::Result<EligibilityReport> {
+        let (evidence, clauses) = self
+            .evaluate_eligibility(campaign, address.0, api_token)
+            .await?;
+
+        Ok(EligibilityReport {
+            eligible: evidence.eligible,
+            clauses,
+        })
+    }
+}
+
+impl Query {
+    /// Evaluates a `campaign`'s [`EligibilityRule`] for an `address`, returning the
+    /// [`EligibilityEvidence`] and the results of the rule's clauses.
+    async fn evaluate_eligibility(
+        &self,
+        campaign: CampaignId,
+        address: Address,
+        api_token: String,
+    ) -> async_graphql::Result<(EligibilityEvidence, Vec<ClauseResult>)> {
+        let Campaign {
+            eligibility:
+                Eligibility {
+                    snapshot_block,
+                    rule,
+                },
+            amount,
+            ..
+        } = self
+            .state
+            .campaigns
+            .get(&campaign)
+            .await?
+            .ok_or_else(|| async_graphql::Error::new(format!("Unknown campaign {campaign}")))?;
+
+        let is_denied = self
+            .state
+            .denylist
+            .contains(&DenylistEntry::ExternalAddress(address.into()))
+            .await?;
 
         let mut runtime = self
-            .0
             .runtime
             .lock()
             .expect("Panics should abort service, so mutex should never be poisoned");
 
         let Parameters {
-            snapshot_block,
-            minimum_balance,
-            ..
+            network, gateway, ..
         } = runtime.application_parameters();
 
-        let query = format!(
-            "{{ \"sqlText\": \"\
-                SELECT BALANCE FROM ETHEREUM.NATIVE_WALLETS \
-                WHERE WALLET_ADDRESS = '{lowercase_address}' AND BLOCK_NUMBER <= {snapshot_block} \
-                ORDER BY BLOCK_NUMBER DESC \
-                LIMIT 1\
-                ;\
-            \" }}"
-        );
-
-        let response = runtime.http_request(
-            http::Request::post(SXT_GATEWAY_URL, query.as_bytes())
-                .with_header("Content-Type", b"application/json")
-                .with_header("Authorization", format!("Bearer {api_token}").as_bytes()),
-        );
-
-        ensure!(
-            response.status == 200,
-            async_graphql::Error::new(format!(
-                "Failed to perform Space-and-Time query. Status-code: {}",
-                response.status
-            ))
-        );
+        let mut evaluator = RuleEvaluator {
+            runtime: &mut runtime,
+            gateway,
+            api_token,
+            network,
+            address,
+            snapshot_block,
+            is_denied,
+            balance: None,
+            clauses: Vec::new(),
+        };
 
-        let result = serde_json::from_slice::<Vec<serde_json::Map<String, serde_json::Value>>>(
-            &response.body,
-        )
-        .map_err(|_| async_graphql::Error::new("Invalid response from Space-and-Time Gateway"))?;
+        let matched_rule = evaluator.evaluate(&rule)?;
+        let eligible = matched_rule.is_some();
 
-        ensure!(
-            result.len() <= 1,
-            async_graphql::Error::new(format!(
-                "Expected at most one query result from Space-and-Time, got {}",
-                result.len()
-            ))
-        );
-
-        if result.is_empty() {
-            Ok(false)
-        } else {
-            ensure!(
-                result[0].len() == 1,
-                async_graphql::Error::new(format!(
-                    "Expected a single result column from Space-and-Time query, got {}",
-                    result[0].len()
-                ))
-            );
+        let evidence = EligibilityEvidence {
+            eligible,
+            balance: evaluator.balance,
+            block: snapshot_block,
+            matched_rule,
+            amount: amount.amount().filter(|_| eligible).unwrap_or(Amount::ZERO),
+        };
 
-            let balance_string = result[0]["BALANCE"].as_str().ok_or_else(|| {
-                async_graphql::Error::new(format!("Query result is not a string: {result:?}"))
-            })?;
+        Ok((evidence, evaluator.clauses))
+    }
+}
+
+/// The result of evaluating a campaign's [`EligibilityRule`] for an address.
+#[derive(async_graphql::SimpleObject)]
+pub struct EligibilityReport {
+    /// Whether the address is eligible.
+    eligible: bool,
+    /// The results of the rule's clauses, in evaluation order, ending with the whole rule.
+    clauses: Vec<ClauseResult>,
+}
+
+/// The result of evaluating one clause of an [`EligibilityRule`].
+#[derive(async_graphql::SimpleObject)]
+pub struct ClauseResult {
+    /// The clause, as an expression in the rule language.
+    clause: String,
+    /// Whether the clause is satisfied.
+    satisfied: bool,
+    /// The value read from Space-and-Time for a single criterion, if there was one.
+    value: Option<String>,
+}
+
+/// Evaluates [`EligibilityRule`]s for an address, querying the Space-and-Time Gateway for each
+/// [`Criterion`].
+///
+/// All clauses are evaluated, even if the result is already known, so that the report is
+/// complete.
+struct RuleEvaluator<'runtime> {
+    runtime: &'runtime mut ServiceRuntime<ApplicationService>,
+    gateway: GatewayConfiguration,
+    api_token: String,
+    network: Network,
+    address: Address,
+    snapshot_block: u64,
+    is_denied: bool,
+    /// The first native token balance read for the address.
+    balance: Option<U256>,
+    clauses: Vec<ClauseResult>,
+}
 
-            let balance = U256::from_str(balance_string).map_err(|_| {
-                async_graphql::Error::new(format!(
-                    "Query result string is not a valid balance value: {balance_string:?}"
+impl RuleEvaluator<'_> {
+    /// Evaluates a `rule` and its sub-rules, recording the result of each clause.
+    ///
+    /// Returns the part of the `rule` that was matched, or `None` if it isn't satisfied. An
+    /// [`EligibilityRule::Any`] is matched by its first satisfied sub-rule, and any other rule
+    /// is matched as a whole.
+    fn evaluate(&mut self, rule: &EligibilityRule) -> async_graphql::Result<Option<String>> {
+        let (matched_rule, value) = match rule {
+            EligibilityRule::Criterion(criterion) => {
+                let (satisfied, value) = self.evaluate_criterion(criterion)?;
+                (satisfied.then(|| rule.to_string()), value)
+            }
+            EligibilityRule::All(rules) => {
+                let mut satisfied = true;
+                for rule in rules {
+                    satisfied &= self.evaluate(rule)?.is_some();
+                }
+                (satisfied.then(|| rule.to_string()), None)
+            }
+            EligibilityRule::Any(rules) => {
+                let mut matched_rule = None;
+                for rule in rules {
+                    let matched_sub_rule = self.evaluate(rule)?;
+                    matched_rule = matched_rule.or(matched_sub_rule);
+                }
+                (matched_rule, None)
+            }
+            EligibilityRule::Not(sub_rule) => (
+                self.evaluate(sub_rule)?.is_none().then(|| rule.to_string()),
+                None,
+            ),
+        };
+
+        self.clauses.push(ClauseResult {
+            clause: rule.to_string(),
+            satisfied: matched_rule.is_some(),
+            value: value.map(|value| value.to_string()),
+        });
+
+        Ok(matched_rule)
+    }
+
+    /// Evaluates a single `criterion`, returning if it's satisfied together with the value read
+    /// from Space-and-Time.
+    fn evaluate_criterion(
+        &mut self,
+        criterion: &Criterion,
+    ) -> async_graphql::Result<(bool, Option<U256>)> {
+        let arguments = QueryArguments::new(&self.network, self.address, self.snapshot_block);
+        let templates = &self.gateway;
+
+        let (sql_text, column) = match criterion {
+            Criterion::Denylisted => return Ok((self.is_denied, None)),
+            Criterion::Allowlisted { .. } => {
+                return Err(async_graphql::Error::new(
+                    "Allowlist membership can only be proven with the claim's allowlist proof",
+                ))
+            }
+            Criterion::LineraMember { .. }
+            | Criterion::LineraTokenBalance { .. }
+            | Criterion::LineraApplicationMember { .. } => {
+                return Err(async_graphql::Error::new(
+                    "Criteria on Linera identities can only be checked by Linera claims",
                 ))
-            })?;
+            }
+            Criterion::CombinedBalance { minimum, snapshots } => {
+                let mut total = U256::ZERO;
+                for snapshot in snapshots {
+                    let arguments =
+                        QueryArguments::new(&snapshot.network, self.address, snapshot.block);
+                    let sql_text = templates.query_template.render(&arguments);
+                    let balance = query_gateway(
+                        self.runtime,
+                        templates,
+                        &self.api_token,
+                        &sql_text,
+                        Some(&templates.balance_column),
+                    )?;
+                    total = total.saturating_add(balance.unwrap_or_default());
+                }
+                self.balance = self.balance.or(Some(total));
+                return Ok((total >= *minimum, Some(total)));
+            }
+            Criterion::Balance { holding, .. } => {
+                let sql_text = match holding {
+                    HoldingRule::AtSnapshot => templates.query_template.render(&arguments),
+                    HoldingRule::Continuous { start_block } => templates
+                        .holding_query_template
+                        .render(&arguments.with_start_block(*start_block)),
+                    HoldingRule::TimeWeightedAverage { start_block } => templates
+                        .average_query_template
+                        .render(&arguments.with_start_block(*start_block)),
+                };
+                (sql_text, Some(templates.balance_column.as_str()))
+            }
+            Criterion::TokenBalance { token, .. } => (
+                templates
+                    .token_balance_query_template
+                    .render(&arguments.with_contract(*token)),
+                Some(templates.balance_column.as_str()),
+            ),
+            Criterion::Activity(ActivityRule::TransactionCount { .. }) => (
+                templates
+                    .transaction_count_query_template
+                    .render(&arguments),
+                None,
+            ),
+            Criterion::Activity(ActivityRule::ContractInteraction { contract }) => (
+                templates
+                    .contract_interaction_query_template
+                    .render(&arguments.with_contract(*contract)),
+                None,
+            ),
+            Criterion::Activity(ActivityRule::FirstSeenBefore { .. }) => {
+                (templates.first_seen_query_template.render(&arguments), None)
+            }
+        };
+
+        let value = query_gateway(
+            self.runtime,
+            &self.gateway,
+            &self.api_token,
+            &sql_text,
+            column,
+        )?;
+
+        let satisfied = match (criterion, value) {
+            (_, None) => false,
+            (
+                Criterion::Balance { minimum, .. } | Criterion::TokenBalance { minimum, .. },
+                Some(balance),
+            ) => balance >= *minimum,
+            (Criterion::Activity(ActivityRule::TransactionCount { minimum }), Some(count)) => {
+                count >= U256::from(*minimum)
+            }
+            (Criterion::Activity(ActivityRule::ContractInteraction { .. }), Some(count)) => {
+                count > U256::ZERO
+            }
+            (Criterion::Activity(ActivityRule::FirstSeenBefore { cutoff_block }), Some(block)) => {
+                block < U256::from(*cutoff_block)
+            }
+            (
+                Criterion::Denylisted
+                | Criterion::Allowlisted { .. }
+                | Criterion::LineraMember { .. }
+                | Criterion::LineraTokenBalance { .. }
+                | Criterion::LineraApplicationMember { .. }
+                | Criterion::CombinedBalance { .. },
+                Some(_),
+            ) => {
+                unreachable!("Criterion is evaluated without a single Gateway query")
+            }
+        };
 
-            Ok(balance >= minimum_balance)
+        if let (Criterion::Balance { .. }, Some(balance)) = (criterion, value) {
+            self.balance = self.balance.or(Some(balance));
         }
+
+        Ok((satisfied, value))
     }
 }
 
+/// Runs an SQL query through the Space-and-Time Gateway, and returns the integer value in its
+/// single result row.
+///
+/// The value is read from the `column`, or from the only column if it's `None`. Returns `None` if
+/// the query has no results, or if the value is `NULL`, which is what aggregate queries return
+/// when no rows match.
+fn query_gateway(
+    runtime: &mut ServiceRuntime<ApplicationService>,
+    gateway: &GatewayConfiguration,
+    api_token: &str,
+    sql_text: &str,
+    column: Option<&str>,
+) -> async_graphql::Result<Option<U256>> {
+    let query = serde_json::json!({ "sqlText": sql_text }).to_string();
+
+    let response = runtime.http_request(
+        http::Request::post(&gateway.url, query.as_bytes())
+            .with_header("Content-Type", b"application/json")
+            .with_header("Authorization", format!("Bearer {api_token}").as_bytes()),
+    );
+
+    ensure!(
+        response.status == 200,
+        async_graphql::Error::new(format!(
+            "Failed to perform Space-and-Time query. Status-code: {}",
+            response.status
+        ))
+    );
+
+    let result = serde_json::from_slice::<Vec<serde_json::Map<String, serde_json::Value>>>(
+        &response.body,
+    )
+    .map_err(|_| async_graphql::Error::new("Invalid response from Space-and-Time Gateway"))?;
+
+    ensure!(
+        result.len() <= 1,
+        async_graphql::Error::new(format!(
+            "Expected at most one query result from Space-and-Time, got {}",
+            result.len()
+        ))
+    );
+
+    let Some(row) = result.first() else {
+        return Ok(None);
+    };
+
+    ensure!(
+        row.len() == 1,
+        async_graphql::Error::new(format!(
+            "Expected a single result column from Space-and-Time query, got {}",
+            row.len()
+        ))
+    );
+
+    let value = match column {
+        Some(column) => row.get(column).ok_or_else(|| {
+            async_graphql::Error::new(format!(
+                "Missing `{column}` column in query result: {result:?}"
+            ))
+        })?,
+        None => row
+            .values()
+            .next()
+            .expect("Row was checked to have a single column"),
+    };
+
+    let value_string = match value {
+        serde_json::Value::Null => return Ok(None),
+        serde_json::Value::String(value_string) => value_string.clone(),
+        serde_json::Value::Number(value_number) => value_number.to_string(),
+        _ => {
+            return Err(async_graphql::Error::new(format!(
+                "Query result is not a string: {result:?}"
+            )))
+        }
+    };
+
+    let value = U256::from_str(&value_string).map_err(|_| {
+        async_graphql::Error::new(format!(
+            "Query result string is not a valid integer value: {value_string:?}"
+        ))
+    })?;
+
+    Ok(Some(value))
+}
+
 /// Root type that defines all the GraphQL mutations available from the service.
 pub struct Mutation;
 
 #[async_graphql::Object]
 impl Mutation {
-    /// Claims an airdrop.
+    /// Creates a new airdrop campaign.
+    async fn create_campaign(&self, campaign: CampaignInput) -> Vec<u8> {
+        bcs::to_bytes(&Operation::CreateCampaign(campaign.into()))
+            .expect("`Operation` should be serializable")
+    }
+
+    /// Claims an airdrop from a campaign.
+    ///
+    /// The claim may be submitted by a relayer, in which case the claimer may have signed an
+    /// optional `relayer_fee` to be paid to the relayer from the airdropped tokens.
+    ///
+    /// The claimer's eligibility is checked through the Space-and-Time Gateway with the
+    /// `api_token`, unless a Proof-of-SQL `balance_proof` is provided. Campaigns with an
+    /// allowlist require an `allowlist_proof` instead.
+    #[allow(clippy::too_many_arguments)]
     async fn air_drop_claim(
         &self,
+        campaign: CampaignId,
         destination: fungible::Account,
-        signature: String,
-        api_token: String,
-    ) -> async_graphql::Result<Vec<u8>> {
-        let signature = signature
-            .parse()
-            .map_err(|_| async_graphql::Error::new("Signature could not be parsed"))?;
-
-        Ok(bcs::to_bytes(&AirDropClaim {
+        signature: EthereumSignature,
+        #[graphql(default)] api_token: String,
+        relayer_fee: Option<Amount>,
+        balance_proof: Option<BalanceProof>,
+        allowlist_proof: Option<AllowlistProof>,
+    ) -> Vec<u8> {
+        bcs::to_bytes(&Operation::Claim(AirDropClaim {
+            campaign,
             signature,
             destination,
             api_token,
+            relayer_fee,
+            balance_proof,
+            allowlist_proof,
+        }))
+        .expect("`Operation` should be serializable")
+    }
+
+    /// Claims multiple airdrops in a single operation.
+    ///
+    /// Each claim is handled independently, so invalid claims are skipped without preventing
+    /// the others from being paid.
+    async fn air_drop_claim_batch(&self, claims: Vec<AirDropClaim>) -> Vec<u8> {
+        bcs::to_bytes(&Operation::ClaimBatch(claims)).expect("`Operation` should be serializable")
+    }
+
+    /// Claims an airdrop from a campaign with Linera members, as the signer of the operation
+    /// or as the chain it's submitted from.
+    async fn linera_claim(&self, campaign: CampaignId, destination: fungible::Account) -> Vec<u8> {
+        bcs::to_bytes(&Operation::LineraClaim {
+            campaign,
+            destination,
         })
-        .expect("`AirDropClaim` should be serializable"))
+        .expect("`Operation` should be serializable")
+    }
+
+    /// Funds a campaign with tokens from the signer's account.
+    async fn fund(&self, campaign: CampaignId, amount: Amount) -> Vec<u8> {
+        bcs::to_bytes(&Operation::Fund { campaign, amount })
+            .expect("`Operation` should be serializable")
     }
-}
 
-/// The URL of the Space-and-Time Gateway API.
-const SXT_GATEWAY_URL: &str = "https://api.spaceandtime.dev/v1/sql";
+    /// Releases the vested tokens of an airdrop.
+    async fn release(
+        &self,
+        context: &async_graphql::Context<'_>,
+        id: AirDropIdInput,
+    ) -> async_graphql::Result<Vec<u8>> {
+        let id = id.into_airdrop_id(context.data::<Network>()?)?;
+
+        Ok(bcs::to_bytes(&Operation::Release { id }).expect("`Operation` should be serializable"))
+    }
+
+    /// Adds and removes entries from the denylist.
+    async fn update_denylist(
+        &self,
+        #[graphql(default)] add: Vec<DenylistEntry>,
+        #[graphql(default)] remove: Vec<DenylistEntry>,
+    ) -> Vec<u8> {
+        bcs::to_bytes(&Operation::UpdateDenylist { add, remove })
+            .expect("`Operation` should be serializable")
+    }
+}
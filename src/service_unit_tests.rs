@@ -1,25 +1,42 @@
 // Copyright (c) Zefchain Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::{Arc, Mutex};
+use std::{
+    env, fs,
+    ops::Deref,
+    path::Path,
+    sync::{Arc, Mutex},
+};
 
 use airdrop_demo::{
-    test_utils::{create_dummy_application_id, create_dummy_token_id, sign_claim},
-    AirDropClaim, Parameters,
+    gateway::{GatewayConfiguration, QueryArguments, QueryTemplate, QueryTemplateError},
+    network::{Network, NetworkSnapshot},
+    test_utils::{
+        create_dummy_application_id, create_dummy_token_id,
+        gateway_responses::{self, balance_response, empty_response, unauthorized_response},
+        sign_claim,
+    },
+    ActivityRule, AirDropClaim, AirDropId, AmountPolicy, Campaign, CampaignId, ClaimWindow,
+    Criterion, Eligibility, EligibilityEvidence, EligibilityRule, HoldingRule, Operation,
+    Parameters,
 };
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{address, Address, U256};
 use k256::ecdsa::SigningKey;
 use linera_sdk::{
     abis::fungible,
-    base::{AccountOwner, ChainId, CryptoHash, Owner},
+    base::{AccountOwner, Amount, ChainId, CryptoHash, Owner, Timestamp},
     bcs, http, serde_json,
     service::MockServiceRuntime,
     util::BlockingWait,
+    views::View,
     Service,
 };
 use rand::rngs::OsRng;
 
-use super::{ApplicationService, SXT_GATEWAY_URL};
+use super::{
+    state::{Application, RejectedClaim},
+    ApplicationService,
+};
 
 /// Tests if a GraphQL query can successfully check if an account is eligible.
 #[test]
@@ -33,12 +50,51 @@ fn query_returns_address_is_eligible() {
         &service,
         &address,
         &api_token,
-        http::Response::ok(format!("[{{ \"BALANCE\": \"{MINIMUM_BALANCE}\" }}]").as_bytes()),
+        balance_response(MINIMUM_BALANCE),
     );
 
     let response = service.handle_query(eligibility_query).blocking_wait();
 
-    assert!(extract_eligibility_from(response));
+    assert_eq!(
+        extract_evidence_from(response),
+        EligibilityEvidence {
+            eligible: true,
+            balance: Some(U256::from(MINIMUM_BALANCE)),
+            block: SNAPSHOT_BLOCK,
+            matched_rule: Some(format!("balance >= {MINIMUM_BALANCE}")),
+            amount: Amount::ONE,
+        }
+    );
+}
+
+/// Tests if the evidence returned for an ineligible address has no matched rule and a zero
+/// amount.
+#[test]
+fn query_returns_evidence_of_ineligible_address() {
+    let service = create_service();
+
+    let address = Address::random();
+    let api_token = "API token".to_owned();
+
+    let eligibility_query = prepare_eligibility_query(
+        &service,
+        &address,
+        &api_token,
+        http::Response::ok(b"[{ \"BALANCE\": \"3\" }]"),
+    );
+
+    let response = service.handle_query(eligibility_query).blocking_wait();
+
+    assert_eq!(
+        extract_evidence_from(response),
+        EligibilityEvidence {
+            eligible: false,
+            balance: Some(U256::from(3)),
+            block: SNAPSHOT_BLOCK,
+            matched_rule: None,
+            amount: Amount::ZERO,
+        }
+    );
 }
 
 /// Tests if a GraphQL query can deny an account's eligibility if it's balance was below the
@@ -56,7 +112,7 @@ fn query_returns_address_with_insufficient_balance_is_not_eligible() {
         &service,
         &address,
         &api_token,
-        http::Response::ok(format!("[{{ \"BALANCE\": \"{insufficient_balance}\" }}]").as_bytes()),
+        balance_response(insufficient_balance),
     );
 
     let response = service.handle_query(eligibility_query).blocking_wait();
@@ -74,17 +130,224 @@ fn query_returns_address_thats_unknown_at_snapshot_is_not_eligible() {
     let api_token = "API token".to_owned();
 
     let eligibility_query =
-        prepare_eligibility_query(&service, &address, &api_token, http::Response::ok(b"[]"));
+        prepare_eligibility_query(&service, &address, &api_token, empty_response());
 
     let response = service.handle_query(eligibility_query).blocking_wait();
 
     assert!(!extract_eligibility_from(response));
 }
 
-/// Tests if a GraphQL query reports query errors.
+/// Tests if a GraphQL query uses the gateway URL, query template and balance column configured in
+/// the [`Parameters`].
 #[test]
-fn query_returns_http_errors() {
-    let service = create_service();
+fn query_uses_configured_gateway() {
+    let service = create_service_with(
+        GatewayConfiguration {
+            url: "https://staging.gateway.test/v1/sql".to_owned(),
+            query_template: QueryTemplate::new(
+                "SELECT AMOUNT FROM STAGING.WALLETS \
+                WHERE ADDRESS = '{address}' AND BLOCK <= {snapshot_block};"
+                    .to_owned(),
+                &[],
+            )
+            .expect("Test query template should be valid"),
+            balance_column: "AMOUNT".to_owned(),
+            ..GatewayConfiguration::default()
+        },
+        EligibilityRule::minimum_balance(U256::from(MINIMUM_BALANCE)),
+    );
+
+    let address = Address::random();
+    let api_token = "API token".to_owned();
+
+    let eligibility_query = prepare_eligibility_query(
+        &service,
+        &address,
+        &api_token,
+        http::Response::ok(format!("[{{ \"AMOUNT\": \"{MINIMUM_BALANCE}\" }}]").as_bytes()),
+    );
+
+    let response = service.handle_query(eligibility_query).blocking_wait();
+
+    assert!(extract_eligibility_from(response));
+}
+
+/// Tests if a GraphQL query checks the minimum balance held throughout a holding period.
+#[test]
+fn query_checks_continuous_holding_period() {
+    let holding = HoldingRule::Continuous {
+        start_block: START_BLOCK,
+    };
+    let service = create_service_with(
+        GatewayConfiguration::default(),
+        EligibilityRule::Criterion(Criterion::Balance {
+            minimum: U256::from(MINIMUM_BALANCE),
+            holding,
+        }),
+    );
+
+    let address = Address::random();
+    let api_token = "API token".to_owned();
+
+    expect_gateway_query(
+        &service,
+        &GatewayConfiguration::default()
+            .holding_query_template
+            .render(&query_arguments(address).with_start_block(START_BLOCK)),
+        &api_token,
+        balance_response(MINIMUM_BALANCE),
+    );
+
+    let eligibility_query = create_eligibility_query(&address, &api_token);
+
+    let response = service.handle_query(eligibility_query).blocking_wait();
+
+    assert!(extract_eligibility_from(response));
+}
+
+/// Tests if a GraphQL query checks the time-weighted average balance over a holding period,
+/// which aggregate queries may return as a number.
+#[test]
+fn query_checks_time_weighted_average_balance() {
+    let service = create_service_with(
+        GatewayConfiguration::default(),
+        EligibilityRule::Criterion(Criterion::Balance {
+            minimum: U256::from(MINIMUM_BALANCE),
+            holding: HoldingRule::TimeWeightedAverage {
+                start_block: START_BLOCK,
+            },
+        }),
+    );
+
+    let address = Address::random();
+    let api_token = "API token".to_owned();
+
+    let insufficient_average = MINIMUM_BALANCE - 1;
+
+    expect_gateway_query(
+        &service,
+        &GatewayConfiguration::default()
+            .average_query_template
+            .render(&query_arguments(address).with_start_block(START_BLOCK)),
+        &api_token,
+        http::Response::ok(format!("[{{ \"BALANCE\": {insufficient_average} }}]").as_bytes()),
+    );
+
+    let eligibility_query = create_eligibility_query(&address, &api_token);
+
+    let response = service.handle_query(eligibility_query).blocking_wait();
+
+    assert!(!extract_eligibility_from(response));
+}
+
+/// Tests if a GraphQL query denies an account's eligibility if it had no balance during the
+/// holding period, in which case the aggregate query returns `NULL`.
+#[test]
+fn query_returns_address_without_balance_in_period_is_not_eligible() {
+    let service = create_service_with(
+        GatewayConfiguration::default(),
+        EligibilityRule::Criterion(Criterion::Balance {
+            minimum: U256::from(MINIMUM_BALANCE),
+            holding: HoldingRule::Continuous {
+                start_block: START_BLOCK,
+            },
+        }),
+    );
+
+    let address = Address::random();
+    let api_token = "API token".to_owned();
+
+    expect_gateway_query(
+        &service,
+        &GatewayConfiguration::default()
+            .holding_query_template
+            .render(&query_arguments(address).with_start_block(START_BLOCK)),
+        &api_token,
+        http::Response::ok(b"[{ \"BALANCE\": null }]"),
+    );
+
+    let eligibility_query = create_eligibility_query(&address, &api_token);
+
+    let response = service.handle_query(eligibility_query).blocking_wait();
+
+    assert!(!extract_eligibility_from(response));
+}
+
+/// Tests if a GraphQL query checks activity rules combined with the balance rule, and accepts an
+/// account that meets all of them.
+#[test]
+fn query_checks_activity_rules() {
+    let contract = Address::random();
+    let service = create_service_with(
+        GatewayConfiguration::default(),
+        EligibilityRule::All(vec![
+            EligibilityRule::minimum_balance(U256::from(MINIMUM_BALANCE)),
+            EligibilityRule::Criterion(Criterion::Activity(ActivityRule::TransactionCount {
+                minimum: 5,
+            })),
+            EligibilityRule::Criterion(Criterion::Activity(ActivityRule::ContractInteraction {
+                contract,
+            })),
+            EligibilityRule::Criterion(Criterion::Activity(ActivityRule::FirstSeenBefore {
+                cutoff_block: 80,
+            })),
+        ]),
+    );
+
+    let address = Address::random();
+    let api_token = "API token".to_owned();
+    let gateway = GatewayConfiguration::default();
+
+    let eligibility_query = prepare_eligibility_query(
+        &service,
+        &address,
+        &api_token,
+        balance_response(MINIMUM_BALANCE),
+    );
+
+    expect_gateway_query(
+        &service,
+        &gateway
+            .transaction_count_query_template
+            .render(&query_arguments(address)),
+        &api_token,
+        http::Response::ok(b"[{ \"TRANSACTION_COUNT\": 7 }]"),
+    );
+    expect_gateway_query(
+        &service,
+        &gateway
+            .contract_interaction_query_template
+            .render(&query_arguments(address).with_contract(contract)),
+        &api_token,
+        http::Response::ok(b"[{ \"INTERACTION_COUNT\": 2 }]"),
+    );
+    expect_gateway_query(
+        &service,
+        &gateway
+            .first_seen_query_template
+            .render(&query_arguments(address)),
+        &api_token,
+        http::Response::ok(b"[{ \"FIRST_SEEN_BLOCK\": \"30\" }]"),
+    );
+
+    let response = service.handle_query(eligibility_query).blocking_wait();
+
+    assert!(extract_eligibility_from(response));
+}
+
+/// Tests if a GraphQL query denies an account's eligibility if it doesn't meet an activity rule,
+/// even if its balance is sufficient.
+#[test]
+fn query_returns_address_with_insufficient_activity_is_not_eligible() {
+    let service = create_service_with(
+        GatewayConfiguration::default(),
+        EligibilityRule::All(vec![
+            EligibilityRule::minimum_balance(U256::from(MINIMUM_BALANCE)),
+            EligibilityRule::Criterion(Criterion::Activity(ActivityRule::TransactionCount {
+                minimum: 5,
+            })),
+        ]),
+    );
 
     let address = Address::random();
     let api_token = "API token".to_owned();
@@ -93,15 +356,345 @@ fn query_returns_http_errors() {
         &service,
         &address,
         &api_token,
-        http::Response::unauthorized(),
+        balance_response(MINIMUM_BALANCE),
+    );
+
+    expect_gateway_query(
+        &service,
+        &GatewayConfiguration::default()
+            .transaction_count_query_template
+            .render(&query_arguments(address)),
+        &api_token,
+        http::Response::ok(b"[{ \"TRANSACTION_COUNT\": \"4\" }]"),
     );
 
     let response = service.handle_query(eligibility_query).blocking_wait();
 
+    assert!(!extract_eligibility_from(response));
+}
+
+/// Tests if a GraphQL query evaluates a rule expression combining multiple criteria, and reports
+/// the result of each clause.
+#[test]
+fn query_reports_rule_expression_clauses() {
+    let token = Address::random();
+    let balance_rule = EligibilityRule::minimum_balance(U256::from(MINIMUM_BALANCE));
+    let token_rule = EligibilityRule::Criterion(Criterion::TokenBalance {
+        token,
+        minimum: U256::from(5),
+    });
+    let holder_rule = EligibilityRule::Any(vec![balance_rule.clone(), token_rule.clone()]);
+    let activity_rule =
+        EligibilityRule::Criterion(Criterion::Activity(ActivityRule::TransactionCount {
+            minimum: 5,
+        }));
+    let denylisted_rule = EligibilityRule::Criterion(Criterion::Denylisted);
+    let allowed_rule = EligibilityRule::Not(Box::new(denylisted_rule.clone()));
+    let rule = EligibilityRule::All(vec![
+        holder_rule.clone(),
+        activity_rule.clone(),
+        allowed_rule.clone(),
+    ]);
+
+    assert_eq!(
+        rule.to_string(),
+        format!(
+            "((balance >= {MINIMUM_BALANCE} OR erc20({token}) >= 5) AND tx_count >= 5 \
+            AND NOT denylisted)"
+        )
+    );
+
+    let service = create_service_with(GatewayConfiguration::default(), rule.clone());
+
+    let address = Address::random();
+    let api_token = "API token".to_owned();
+    let gateway = GatewayConfiguration::default();
+    let insufficient_balance = MINIMUM_BALANCE - 1;
+
+    expect_gateway_query(
+        &service,
+        &gateway.query_template.render(&query_arguments(address)),
+        &api_token,
+        balance_response(insufficient_balance),
+    );
+    expect_gateway_query(
+        &service,
+        &gateway
+            .token_balance_query_template
+            .render(&query_arguments(address).with_contract(token)),
+        &api_token,
+        http::Response::ok(b"[{ \"BALANCE\": \"5\" }]"),
+    );
+    expect_gateway_query(
+        &service,
+        &gateway
+            .transaction_count_query_template
+            .render(&query_arguments(address)),
+        &api_token,
+        http::Response::ok(b"[{ \"TRANSACTION_COUNT\": 5 }]"),
+    );
+
+    let json_query = format!(
+        "{{ \"query\":
+            \"query {{ \
+                eligibilityReport( \
+                    campaign: {CAMPAIGN}, \
+                    address: \\\"{address}\\\", \
+                    apiToken: \\\"{api_token}\\\" \
+                ) {{ eligible clauses {{ clause satisfied value }} }} \
+            }}\"
+        }}"
+    );
+    let query = serde_json::from_str(&json_query).expect("Failed to deserialize GraphQL query");
+
+    let response = service.handle_query(query).blocking_wait();
+
+    assert_eq!(response.errors.len(), 0, "{:?}", response.errors);
+    assert_eq!(
+        response
+            .data
+            .into_json()
+            .expect("Response data should be representable as JSON"),
+        serde_json::json!({
+            "eligibilityReport": {
+                "eligible": true,
+                "clauses": [
+                    {
+                        "clause": balance_rule.to_string(),
+                        "satisfied": false,
+                        "value": insufficient_balance.to_string(),
+                    },
+                    { "clause": token_rule.to_string(), "satisfied": true, "value": "5" },
+                    { "clause": holder_rule.to_string(), "satisfied": true, "value": null },
+                    { "clause": activity_rule.to_string(), "satisfied": true, "value": "5" },
+                    { "clause": denylisted_rule.to_string(), "satisfied": false, "value": null },
+                    { "clause": allowed_rule.to_string(), "satisfied": true, "value": null },
+                    { "clause": rule.to_string(), "satisfied": true, "value": null },
+                ],
+            }
+        })
+    );
+}
+
+/// Tests if a GraphQL query sums the balances of an address across multiple networks, each read
+/// at its own snapshot block.
+#[test]
+fn query_sums_balances_across_networks() {
+    let snapshots = vec![
+        NetworkSnapshot {
+            network: Network::Ethereum,
+            block: SNAPSHOT_BLOCK,
+        },
+        NetworkSnapshot {
+            network: Network::Base,
+            block: 2_000,
+        },
+    ];
+    let service = create_service_with(
+        GatewayConfiguration::default(),
+        EligibilityRule::Criterion(Criterion::CombinedBalance {
+            minimum: U256::from(MINIMUM_BALANCE),
+            snapshots: snapshots.clone(),
+        }),
+    );
+
+    let address = Address::random();
+    let api_token = "API token".to_owned();
+    let gateway = GatewayConfiguration::default();
+
+    let partial_balances = [MINIMUM_BALANCE - 4, 4];
+
+    for (snapshot, balance) in snapshots.iter().zip(partial_balances) {
+        let sql_query = gateway.query_template.render(&QueryArguments::new(
+            &snapshot.network,
+            address,
+            snapshot.block,
+        ));
+
+        assert!(sql_query.contains(&format!("FROM {}.", snapshot.network.schema())));
+
+        expect_gateway_query(&service, &sql_query, &api_token, balance_response(balance));
+    }
+
+    let eligibility_query = create_eligibility_query(&address, &api_token);
+
+    let response = service.handle_query(eligibility_query).blocking_wait();
+
+    assert!(extract_eligibility_from(response));
+}
+
+/// Tests if query templates without the required placeholders are rejected.
+#[test]
+fn query_template_requires_placeholders() {
+    assert_eq!(
+        QueryTemplate::new(
+            "SELECT BALANCE FROM WALLETS WHERE ADDRESS = '{address}';".to_owned(),
+            &[]
+        ),
+        Err(QueryTemplateError::MissingPlaceholder(
+            QueryTemplate::SNAPSHOT_BLOCK
+        ))
+    );
+    assert_eq!(
+        QueryTemplate::new(
+            "SELECT {column} FROM WALLETS \
+            WHERE ADDRESS = '{address}' AND BLOCK <= {snapshot_block};"
+                .to_owned(),
+            &[]
+        ),
+        Err(QueryTemplateError::UnknownPlaceholder("column".to_owned()))
+    );
+}
+
+/// Tests if query templates over a holding period require the start block placeholder, and if
+/// other templates can't contain it.
+#[test]
+fn query_template_requires_start_block_for_holding_periods() {
+    let holding_template = "SELECT MIN(BALANCE) AS BALANCE FROM WALLETS \
+        WHERE ADDRESS = '{address}' AND BLOCK >= {start_block} AND BLOCK <= {snapshot_block};";
+    let snapshot_template =
+        "SELECT BALANCE FROM WALLETS WHERE ADDRESS = '{address}' AND BLOCK <= {snapshot_block};";
+
+    assert!(QueryTemplate::new(holding_template.to_owned(), &[QueryTemplate::START_BLOCK]).is_ok());
+    assert_eq!(
+        QueryTemplate::new(snapshot_template.to_owned(), &[QueryTemplate::START_BLOCK]),
+        Err(QueryTemplateError::MissingPlaceholder(
+            QueryTemplate::START_BLOCK
+        ))
+    );
+    assert_eq!(
+        QueryTemplate::new(holding_template.to_owned(), &[]),
+        Err(QueryTemplateError::UnsupportedPlaceholder(
+            QueryTemplate::START_BLOCK.to_owned()
+        ))
+    );
+}
+
+/// Tests if query templates on token balances and contract interactions require the contract
+/// placeholder, and if other templates can't contain it.
+#[test]
+fn query_template_requires_contract_for_contract_queries() {
+    let contract_template = "SELECT COUNT(*) AS INTERACTION_COUNT FROM TRANSACTIONS \
+        WHERE FROM_ADDRESS = '{address}' AND TO_ADDRESS = '{contract}' \
+        AND BLOCK <= {snapshot_block};";
+    let address_template = "SELECT COUNT(*) AS INTERACTION_COUNT FROM TRANSACTIONS \
+        WHERE FROM_ADDRESS = '{address}' AND BLOCK <= {snapshot_block};";
+
+    assert!(QueryTemplate::new(contract_template.to_owned(), &[QueryTemplate::CONTRACT]).is_ok());
+    assert_eq!(
+        QueryTemplate::new(address_template.to_owned(), &[QueryTemplate::CONTRACT]),
+        Err(QueryTemplateError::MissingPlaceholder(
+            QueryTemplate::CONTRACT
+        ))
+    );
+    assert_eq!(
+        QueryTemplate::new(contract_template.to_owned(), &[]),
+        Err(QueryTemplateError::UnsupportedPlaceholder(
+            QueryTemplate::CONTRACT.to_owned()
+        ))
+    );
+}
+
+/// Tests if the default Gateway configuration has the placeholders required by each query.
+#[test]
+fn default_query_templates_are_valid() {
+    for (name, template, placeholders) in GatewayConfiguration::default().query_templates() {
+        assert_eq!(template.validate(placeholders), Ok(()), "Invalid `{name}`");
+    }
+}
+
+/// Tests if a GraphQL query reports query errors.
+#[test]
+fn query_returns_http_errors() {
+    let service = create_service();
+
+    let address = Address::random();
+    let api_token = "API token".to_owned();
+
+    let eligibility_query =
+        prepare_eligibility_query(&service, &address, &api_token, unauthorized_response());
+
+    let response = service.handle_query(eligibility_query).blocking_wait();
+
     assert!(matches!(response.data, async_graphql::Value::Null));
     assert_eq!(response.errors.len(), 1);
 }
 
+/// Tests if the addresses of airdrops are displayed with the checksum of the network configured in
+/// the [`Parameters`].
+#[test]
+fn query_displays_addresses_with_configured_checksum() {
+    let network = Network::Other {
+        schema: "ROOTSTOCK".to_owned(),
+        chain_id: 30,
+    };
+    let address = Address::random();
+    let service = create_service_with_rejected_claim(network.clone(), address);
+    let query = async_graphql::Request::new("query { rejectedClaims { id { address } } }");
+
+    let response = service.handle_query(query).blocking_wait();
+
+    assert_eq!(
+        response.data,
+        async_graphql::value!({
+            "rejectedClaims": [{ "id": { "address": address.to_checksum(Some(network.chain_id())) } }],
+        })
+    );
+}
+
+/// Tests if the address of an airdrop displayed with the configured network's checksum is accepted
+/// back as an input, but not with the checksum of another network.
+#[test]
+fn displayed_airdrop_id_is_accepted_as_input() {
+    let address = address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+    let service = create_service_with_rejected_claim(Network::Ethereum, address);
+    let query = async_graphql::Request::new("query { rejectedClaims { id { address } } }");
+
+    let response = service.handle_query(query).blocking_wait();
+    let displayed_address = response
+        .data
+        .into_json()
+        .expect("Unexpected response from service")["rejectedClaims"][0]["id"]["address"]
+        .as_str()
+        .expect("Missing displayed address")
+        .to_owned();
+
+    assert_eq!(
+        displayed_address,
+        "0x5aaeB6053f3E94C9b9A09f33669435e7Ef1bEAed"
+    );
+    assert_ne!(displayed_address, address.to_checksum(None));
+
+    let release_mutation = |address: &str| {
+        let mutation = format!(
+            "mutation {{ release(id: {{ campaign: {CAMPAIGN}, address: \"{address}\" }}) }}"
+        );
+        service
+            .handle_query(async_graphql::Request::new(mutation))
+            .blocking_wait()
+    };
+
+    let response = release_mutation(&displayed_address);
+    let expected_bytes = bcs::to_bytes(&Operation::Release {
+        id: AirDropId::new(CAMPAIGN, address),
+    })
+    .expect("`Operation` should be serializable");
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        async_graphql::value!({ "release": expected_bytes }),
+    );
+
+    let response = release_mutation(&address.to_checksum(Some(30)));
+
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(
+        response.errors[0].message,
+        "Ethereum address has an invalid checksum"
+    );
+}
+
 /// Tests if a GraphQL mutation can be used to create an [`AirDropClaim`] operation.
 #[test]
 fn mutation_generates_air_drop_claim() {
@@ -117,13 +710,14 @@ fn mutation_generates_air_drop_claim() {
     let api_token = "API token".to_owned();
     let application_id = create_dummy_application_id("zk-airdrop", 1);
     let signing_key = SigningKey::random(&mut OsRng);
-    let signature = sign_claim(&signing_key, application_id, destination);
+    let signature = sign_claim(&signing_key, application_id, CAMPAIGN, destination, None);
     let signature_string = hex::encode(signature.as_bytes());
 
     let json_query = format!(
         "{{ \"query\":
             \"mutation {{ \
                 airDropClaim( \
+                    campaign: {CAMPAIGN}, \
                     signature: \\\"{signature_string}\\\", \
                     destination: {{ \
                         chainId: \\\"{chain_id}\\\", \
@@ -160,75 +754,200 @@ fn mutation_generates_air_drop_claim() {
         })
         .collect::<Vec<u8>>();
 
-    let mut operation = bcs::from_bytes::<AirDropClaim>(&serialized_operation)
-        .expect("Failed to deserialize returned operation");
-
-    operation.signature = operation.signature.with_parity_bool();
+    let Operation::Claim(claim) = bcs::from_bytes::<Operation>(&serialized_operation)
+        .expect("Failed to deserialize returned operation")
+    else {
+        panic!("Returned operation is not an `Operation::Claim`");
+    };
 
-    let expected_operation = AirDropClaim {
-        signature,
+    let expected_claim = AirDropClaim {
+        campaign: CAMPAIGN,
+        signature: signature.into(),
         destination: fungible::Account {
             chain_id,
             owner: claimer,
         },
         api_token,
+        relayer_fee: None,
+        balance_proof: None,
+        allowlist_proof: None,
     };
 
-    assert_eq!(operation, expected_operation);
+    assert_eq!(claim, expected_claim);
 }
 
-/// Creates an [`ApplicationService`] instance.
-fn create_service() -> ApplicationService {
+/// Tests if `schema.graphql` has the service's current GraphQL schema, so that client code can be
+/// generated offline and changes to the schema show up in review.
+///
+/// The file is written instead if the `UPDATE_GRAPHQL_SCHEMA` environment variable is set.
+#[test]
+fn exported_schema_is_up_to_date() {
+    let sdl = create_service().schema.sdl();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("schema.graphql");
+
+    if env::var_os("UPDATE_GRAPHQL_SCHEMA").is_some() {
+        fs::write(&path, sdl).expect("Failed to write GraphQL schema");
+        return;
+    }
+
+    let exported_sdl = fs::read_to_string(&path).expect(
+        "Failed to read `schema.graphql`, run the tests with `UPDATE_GRAPHQL_SCHEMA=1` to create it",
+    );
+
+    assert!(
+        exported_sdl == sdl,
+        "`schema.graphql` is outdated, run the tests with `UPDATE_GRAPHQL_SCHEMA=1` to update it"
+    );
+}
+
+/// An [`ApplicationService`] with a handle to its mock runtime, to configure the runtime's
+/// responses.
+struct TestService {
+    service: ApplicationService,
+    runtime: Arc<Mutex<MockServiceRuntime<ApplicationService>>>,
+}
+
+impl Deref for TestService {
+    type Target = ApplicationService;
+
+    fn deref(&self) -> &Self::Target {
+        &self.service
+    }
+}
+
+/// Creates an [`ApplicationService`] instance with a [`CAMPAIGN`] to check eligibility against,
+/// using the default [`GatewayConfiguration`].
+fn create_service() -> TestService {
+    create_service_with(
+        GatewayConfiguration::default(),
+        EligibilityRule::minimum_balance(U256::from(MINIMUM_BALANCE)),
+    )
+}
+
+/// Creates an [`ApplicationService`] instance with a [`CAMPAIGN`] to check eligibility against,
+/// using a custom `gateway` and eligibility `rule`.
+fn create_service_with(gateway: GatewayConfiguration, rule: EligibilityRule) -> TestService {
     let runtime = MockServiceRuntime::new().with_application_parameters(Parameters {
         token_id: create_dummy_token_id(),
-        snapshot_block: 100,
-        minimum_balance: U256::from(MINIMUM_BALANCE),
+        admin: Owner(CryptoHash::test_hash("admin")),
+        network: NETWORK.clone(),
+        proof_of_sql_commitments: None,
+        gateway,
     });
 
-    ApplicationService {
-        runtime: Arc::new(Mutex::new(runtime)),
+    let mut state = Application::load(runtime.root_view_storage_context())
+        .blocking_wait()
+        .expect("Failed to read from mock key value store");
+
+    state
+        .campaigns
+        .insert(
+            &CAMPAIGN,
+            Campaign {
+                eligibility: Eligibility {
+                    snapshot_block: SNAPSHOT_BLOCK,
+                    rule,
+                },
+                amount: AmountPolicy::Fixed(Amount::ONE),
+                window: ClaimWindow {
+                    start: Timestamp::from(0),
+                    end: Timestamp::from(u64::MAX),
+                },
+                vesting: None,
+            },
+        )
+        .expect("Failed to write test campaign");
+
+    let runtime = Arc::new(Mutex::new(runtime));
+
+    TestService {
+        service: ApplicationService::with_state(state, runtime.clone()),
+        runtime,
     }
 }
 
+/// Creates an [`ApplicationService`] instance configured with a `network`, with a rejected claim
+/// from an `address`.
+fn create_service_with_rejected_claim(network: Network, address: Address) -> ApplicationService {
+    let runtime = MockServiceRuntime::new().with_application_parameters(Parameters {
+        token_id: create_dummy_token_id(),
+        admin: Owner(CryptoHash::test_hash("admin")),
+        network,
+        proof_of_sql_commitments: None,
+        gateway: GatewayConfiguration::default(),
+    });
+
+    let mut state = Application::load(runtime.root_view_storage_context())
+        .blocking_wait()
+        .expect("Failed to read from mock key value store");
+
+    state.rejected_claims.push(RejectedClaim {
+        id: AirDropId::new(CAMPAIGN, address),
+        destination: fungible::Account {
+            chain_id: ChainId(CryptoHash::test_hash("chain ID")),
+            owner: AccountOwner::User(Owner(CryptoHash::test_hash("claimer"))),
+        },
+        reason: "Claimer is not eligible for the airdrop".to_owned(),
+        timestamp: Timestamp::from(0),
+    });
+
+    ApplicationService::with_state(state, Arc::new(Mutex::new(runtime)))
+}
+
+/// The campaign used in the tests.
+const CAMPAIGN: CampaignId = CampaignId(0);
+
+/// The network configured in the tests' [`Parameters`].
+static NETWORK: Network = Network::Ethereum;
+
+/// The snapshot block of the campaign used in the tests.
+const SNAPSHOT_BLOCK: u64 = 100;
+
+/// The first block of the holding periods used in the tests.
+const START_BLOCK: u64 = 50;
+
 /// The minimum balance to be eligible for an airdrop in the tests.
 const MINIMUM_BALANCE: usize = 10;
 
 /// Prepares an [`async_graphql::Request`] to the service to `checkEligibility` of an [`Address`].
 ///
 /// Configures the `service`'s mock runtime to return the expected `query_response` when the HTTP
-/// query is made.
+/// query for the balance at the snapshot block is made.
 fn prepare_eligibility_query(
-    service: &ApplicationService,
+    service: &TestService,
     address: &Address,
     api_token: &str,
     query_response: http::Response,
 ) -> async_graphql::Request {
-    let mut runtime = service
+    let gateway = service
         .runtime
         .lock()
-        .expect("Test should abort on panic, so mutex should never be poisoned");
+        .expect("Test should abort on panic, so mutex should never be poisoned")
+        .application_parameters()
+        .gateway;
+    let sql_query = gateway.query_template.render(&query_arguments(*address));
 
-    let snapshot_block = runtime.application_parameters().snapshot_block;
-    let sql_query = format!(
-        "SELECT BALANCE FROM ETHEREUM.NATIVE_WALLETS \
-        WHERE WALLET_ADDRESS = '0x{}' AND BLOCK_NUMBER <= {snapshot_block} \
-        ORDER BY BLOCK_NUMBER DESC \
-        LIMIT 1;",
-        hex::encode(address.as_slice())
-    );
-    let expected_query = format!(r#"{{ "sqlText": "{sql_query}" }}"#);
+    expect_gateway_query(service, &sql_query, api_token, query_response);
 
-    runtime.add_expected_http_request(
-        http::Request::post(SXT_GATEWAY_URL, expected_query.as_bytes())
-            .with_header("Content-Type", b"application/json")
-            .with_header("Authorization", format!("Bearer {api_token}").as_bytes()),
-        query_response,
-    );
+    create_eligibility_query(address, api_token)
+}
 
+/// Creates an [`async_graphql::Request`] to the service to `checkEligibility` of an [`Address`].
+fn create_eligibility_query(address: &Address, api_token: &str) -> async_graphql::Request {
     let json_query = format!(
         "{{ \"query\":
             \"query {{ \
-                checkEligibility(address: \\\"{address}\\\", apiToken: \\\"{api_token}\\\") \
+                checkEligibility( \
+                    campaign: {CAMPAIGN}, \
+                    address: \\\"{address}\\\", \
+                    apiToken: \\\"{api_token}\\\" \
+                ) {{ \
+                    eligible \
+                    balance \
+                    block \
+                    matched_rule: matchedRule \
+                    amount \
+                }} \
             }}\"
         }}"
     );
@@ -236,9 +955,44 @@ fn prepare_eligibility_query(
     serde_json::from_str(&json_query).expect("Failed to deserialize GraphQL query")
 }
 
+/// Creates the [`QueryArguments`] to render the queries for an `address` at the
+/// [`SNAPSHOT_BLOCK`] on the [`NETWORK`].
+fn query_arguments(address: Address) -> QueryArguments<'static> {
+    QueryArguments::new(&NETWORK, address, SNAPSHOT_BLOCK)
+}
+
+/// Configures the `service`'s mock runtime to return the `query_response` when the Gateway is
+/// queried with the `sql_query`.
+fn expect_gateway_query(
+    service: &TestService,
+    sql_query: &str,
+    api_token: &str,
+    query_response: http::Response,
+) {
+    let mut runtime = service
+        .runtime
+        .lock()
+        .expect("Test should abort on panic, so mutex should never be poisoned");
+    let gateway = runtime.application_parameters().gateway;
+
+    gateway_responses::expect_gateway_query(
+        &mut runtime,
+        &gateway,
+        sql_query,
+        api_token,
+        query_response,
+    );
+}
+
 /// Parses the [`async_graphql::Response`] of `checkEligibility` to extract the `true` or `false`
 /// value that indicates the eligibility.
 fn extract_eligibility_from(response: async_graphql::Response) -> bool {
+    extract_evidence_from(response).eligible
+}
+
+/// Parses the [`async_graphql::Response`] of `checkEligibility` to extract the
+/// [`EligibilityEvidence`].
+fn extract_evidence_from(response: async_graphql::Response) -> EligibilityEvidence {
     assert_eq!(
         response.errors.len(),
         0,
@@ -256,9 +1010,6 @@ fn extract_eligibility_from(response: async_graphql::Response) -> bool {
         "Expected a single item in response data: {data:?}"
     );
 
-    let async_graphql::Value::Boolean(is_eligible) = data["checkEligibility"] else {
-        panic!("Unexpected `checkEligibility` result: {data:?}");
-    };
-
-    is_eligible
+    async_graphql::from_value(data["checkEligibility"].clone())
+        .unwrap_or_else(|_| panic!("Unexpected `checkEligibility` result: {data:?}"))
 }
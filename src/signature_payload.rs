@@ -1,10 +1,15 @@
 // Copyright (c) Zefchain Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use alloy_primitives::U256;
 use alloy_sol_types::{eip712_domain, sol, Eip712Domain};
-use linera_sdk::{abis::fungible, base::ApplicationId, bcs};
+use linera_sdk::{
+    abis::fungible,
+    base::{Amount, ApplicationId},
+    bcs,
+};
 
-use crate::{ApplicationAbi, ETHEREUM_MAINNET_CHAIN_ID};
+use crate::{ApplicationAbi, CampaignId, ETHEREUM_MAINNET_CHAIN_ID};
 
 /// The EIP-712 domain for this application.
 pub const AIRDROP_CLAIM_DOMAIN: Eip712Domain = eip712_domain! {
@@ -17,7 +22,9 @@ sol! {
     /// EIP-712 representation of an airdrop claim.
     struct AirDropClaim {
         string appId;
+        uint32 campaignId;
         FungibleAccount claimer;
+        uint256 relayerFee;
     }
 
     /// EIP-712 representation of a destination account.
@@ -29,13 +36,20 @@ sol! {
 
 impl AirDropClaim {
     /// Creates a new [`AirDropClaim`] to be used in a signature's payload.
-    pub fn new(application_id: ApplicationId<ApplicationAbi>, claimer: &fungible::Account) -> Self {
+    pub fn new(
+        application_id: ApplicationId<ApplicationAbi>,
+        campaign: CampaignId,
+        claimer: &fungible::Account,
+        relayer_fee: Option<Amount>,
+    ) -> Self {
         let application_id_bytes =
             bcs::to_bytes(&application_id).expect("`ApplicationId`s should be serializable");
 
         AirDropClaim {
             appId: hex::encode(application_id_bytes),
+            campaignId: campaign.0,
             claimer: claimer.into(),
+            relayerFee: U256::from(u128::from(relayer_fee.unwrap_or(Amount::ZERO))),
         }
     }
 }
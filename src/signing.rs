@@ -0,0 +1,52 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Signing and verification of the EIP-712 signatures that authorize airdrop claims.
+
+use alloy_primitives::{Address, Signature, SignatureError};
+use alloy_sol_types::SolStruct;
+use k256::ecdsa::SigningKey;
+use linera_sdk::{
+    abis::fungible,
+    base::{Amount, ApplicationId},
+};
+
+use crate::{
+    signature_payload::{self, AIRDROP_CLAIM_DOMAIN},
+    ApplicationAbi, CampaignId,
+};
+
+/// Creates a [`Signature`] for an airdrop claim.
+pub fn sign_claim(
+    signer: &SigningKey,
+    application_id: ApplicationId<ApplicationAbi>,
+    campaign: CampaignId,
+    claimer: fungible::Account,
+    relayer_fee: Option<Amount>,
+) -> Signature {
+    let payload =
+        signature_payload::AirDropClaim::new(application_id, campaign, &claimer, relayer_fee);
+
+    let hash = payload.eip712_signing_hash(&AIRDROP_CLAIM_DOMAIN);
+
+    signer
+        .sign_prehash_recoverable(hash.as_slice())
+        .expect("Payload hash should be signable with `SigningKey`")
+        .into()
+}
+
+/// Recovers the Ethereum [`Address`] that created the `signature` for an airdrop claim.
+pub fn recover_claim_signer(
+    signature: &Signature,
+    application_id: ApplicationId<ApplicationAbi>,
+    campaign: CampaignId,
+    claimer: &fungible::Account,
+    relayer_fee: Option<Amount>,
+) -> Result<Address, SignatureError> {
+    let payload =
+        signature_payload::AirDropClaim::new(application_id, campaign, claimer, relayer_fee);
+
+    let hash = payload.eip712_signing_hash(&AIRDROP_CLAIM_DOMAIN);
+
+    signature.recover_address_from_prehash(&hash)
+}
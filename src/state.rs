@@ -1,12 +1,90 @@
 // Copyright (c) Zefchain Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use airdrop_demo::AirDropId;
-use linera_sdk::views::{linera_views, RootView, SetView, ViewStorageContext};
+use airdrop_demo::{
+    AirDropId, Campaign, CampaignId, DenylistEntry, EligibilityEvidence, LineraAirDropId,
+};
+use linera_sdk::{
+    abis::fungible::Account,
+    base::{AccountOwner, Amount, Timestamp},
+    views::{linera_views, LogView, MapView, RegisterView, RootView, SetView, ViewStorageContext},
+};
+use serde::{Deserialize, Serialize};
 
 /// The application state.
 #[derive(RootView, async_graphql::SimpleObject)]
 #[view(context = "ViewStorageContext")]
 pub struct Application {
-    pub handled_airdrops: SetView<AirDropId>,
+    pub next_campaign_id: RegisterView<CampaignId>,
+    pub campaigns: MapView<CampaignId, Campaign>,
+    pub budgets: MapView<CampaignId, Budget>,
+    pub handled_airdrops: MapView<AirDropId, ClaimRecord>,
+    pub linera_airdrops: MapView<LineraAirDropId, ClaimRecord>,
+    pub deposits: LogView<Deposit>,
+    pub funders: MapView<AccountOwner, Amount>,
+    pub vesting_positions: MapView<AirDropId, VestingPosition>,
+    pub denylist: SetView<DenylistEntry>,
+    pub rejected_claims: LogView<RejectedClaim>,
+}
+
+/// The tokens funded into and paid by a campaign.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, async_graphql::SimpleObject,
+)]
+#[graphql(complex)]
+pub struct Budget {
+    pub funded: Amount,
+    pub paid: Amount,
+}
+
+impl Budget {
+    /// Returns the amount of tokens that are still available to be paid.
+    pub fn remaining_amount(&self) -> Amount {
+        self.funded.saturating_sub(self.paid)
+    }
+}
+
+#[async_graphql::ComplexObject]
+impl Budget {
+    /// The amount of tokens that are still available to be paid.
+    async fn remaining(&self) -> Amount {
+        self.remaining_amount()
+    }
+}
+
+/// A record of tokens deposited into a campaign's budget.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, async_graphql::SimpleObject)]
+pub struct Deposit {
+    pub campaign: CampaignId,
+    pub funder: AccountOwner,
+    pub amount: Amount,
+    pub timestamp: Timestamp,
+}
+
+/// The tokens of an approved airdrop that are released according to a vesting schedule.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, async_graphql::SimpleObject)]
+pub struct VestingPosition {
+    pub destination: Account,
+    pub total: Amount,
+    pub released: Amount,
+    pub start: Timestamp,
+}
+
+/// A record of an approved claim, with the evidence of the claimer's eligibility.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, async_graphql::SimpleObject)]
+pub struct ClaimRecord {
+    pub destination: Account,
+    pub amount: Amount,
+    pub relayer_fee: Option<Amount>,
+    pub approved_at: Timestamp,
+    pub evidence: EligibilityEvidence,
+}
+
+/// A record of a claim that was rejected by the creator chain.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, async_graphql::SimpleObject)]
+pub struct RejectedClaim {
+    pub id: AirDropId,
+    pub destination: Account,
+    pub reason: String,
+    pub timestamp: Timestamp,
 }
@@ -0,0 +1,107 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A local stand-in for the Space-and-Time prover, which commits to a fixture table of balances
+//! and proves balance queries over it.
+
+use alloy_primitives::Address;
+use linera_sdk::bcs;
+use proof_of_sql::{
+    base::{
+        commitment::{InnerProductProof, TableCommitment},
+        database::{
+            owned_table_utility::{bigint, owned_table, varchar},
+            OwnedTableTestAccessor, TableRef, TestAccessor,
+        },
+    },
+    sql::{parse::QueryExpr, proof::VerifiableQueryResult},
+};
+
+use super::mock_gateway::BalanceRecord;
+use crate::{
+    balance_proof::{balance_query, BalanceProof, TableCommitments},
+    network::Network,
+};
+
+/// A network's `NATIVE_WALLETS` table with fixture [`BalanceRecord`]s, together with the
+/// commitments that the [`BalanceProof`]s over it are verified against.
+pub struct MockProver {
+    network: Network,
+    accessor: OwnedTableTestAccessor<'static, InnerProductProof>,
+    commitments: TableCommitments,
+}
+
+impl MockProver {
+    /// Creates a new [`MockProver`] for the `network`'s table with the `records`.
+    pub fn new(network: Network, records: impl IntoIterator<Item = BalanceRecord>) -> Self {
+        let records = records.into_iter().collect::<Vec<_>>();
+        let table_ref = format!("{}.NATIVE_WALLETS", network.schema())
+            .parse::<TableRef>()
+            .expect("Failed to parse balance table reference");
+        let table = owned_table([
+            varchar(
+                "WALLET_ADDRESS",
+                records
+                    .iter()
+                    .map(|record| record.address.to_string().to_lowercase()),
+            ),
+            bigint(
+                "BLOCK_NUMBER",
+                records.iter().map(|record| {
+                    i64::try_from(record.block).expect("Block number is too large for the table")
+                }),
+            ),
+            varchar(
+                "BALANCE",
+                records.iter().map(|record| record.balance.to_string()),
+            ),
+        ]);
+
+        let commitments = TableCommitments::from_iter([(
+            table_ref,
+            TableCommitment::from_owned_table_with_offset(&table, 0, &()),
+        )]);
+
+        let mut accessor = OwnedTableTestAccessor::new_empty_with_setup(());
+        accessor.add_table(table_ref, table, 0);
+
+        MockProver {
+            network,
+            accessor,
+            commitments,
+        }
+    }
+
+    /// Returns the BCS serialized [`TableCommitments`] to the table, to be used in the
+    /// application's parameters.
+    pub fn serialized_commitments(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.commitments).expect("Table commitments should be serializable")
+    }
+
+    /// Proves the balance query of an `address` at the `snapshot_block`.
+    pub fn prove(&self, address: Address, snapshot_block: u64) -> BalanceProof {
+        let query = QueryExpr::try_new(
+            balance_query(&self.network, address, snapshot_block)
+                .parse()
+                .expect("Failed to parse balance query"),
+            self.network
+                .schema()
+                .parse()
+                .expect("Failed to parse network schema"),
+            &self.accessor,
+        )
+        .expect("Failed to plan balance query");
+
+        let verifiable_result = VerifiableQueryResult::<InnerProductProof>::new(
+            query.proof_expr(),
+            &self.accessor,
+            &(),
+        );
+
+        BalanceProof {
+            snapshot_block,
+            verifiable_result: bcs::to_bytes(&verifiable_result)
+                .expect("Verifiable query result should be serializable"),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A builder of test campaigns, and of the deployment of the airdrop application that runs them.
+
+use alloy_primitives::U256;
+use linera_sdk::base::{Amount, Timestamp};
+
+use crate::{
+    gateway::GatewayConfiguration, network::Network, AmountPolicy, Campaign, ClaimWindow,
+    Eligibility, EligibilityRule, VestingSchedule,
+};
+
+/// A builder of a [`Campaign`] and of the [`Parameters`] of the application that runs it.
+///
+/// By default, the campaign pays [`Amount::ONE`] per claim, accepts claims at any time, has no
+/// vesting schedule and is funded with 100 tokens when deployed.
+///
+/// [`Parameters`]: crate::Parameters
+#[derive(Clone, Debug)]
+pub struct CampaignBuilder {
+    pub(super) campaign: Campaign,
+    pub(super) funding: Amount,
+    pub(super) network: Network,
+    pub(super) gateway: GatewayConfiguration,
+    pub(super) proof_of_sql_commitments: Option<Vec<u8>>,
+}
+
+impl CampaignBuilder {
+    /// Creates a [`CampaignBuilder`] for a campaign with an eligibility `rule` evaluated at the
+    /// `snapshot_block`.
+    pub fn new(snapshot_block: u64, rule: EligibilityRule) -> Self {
+        CampaignBuilder {
+            campaign: Campaign {
+                eligibility: Eligibility {
+                    snapshot_block,
+                    rule,
+                },
+                amount: AmountPolicy::Fixed(Amount::ONE),
+                window: ClaimWindow {
+                    start: Timestamp::from(0),
+                    end: Timestamp::from(u64::MAX),
+                },
+                vesting: None,
+            },
+            funding: Amount::from_tokens(100),
+            network: Network::Ethereum,
+            gateway: GatewayConfiguration::default(),
+            proof_of_sql_commitments: None,
+        }
+    }
+
+    /// Creates a [`CampaignBuilder`] for a campaign where addresses holding at least the
+    /// `minimum` balance at the `snapshot_block` are eligible.
+    pub fn minimum_balance(snapshot_block: u64, minimum: U256) -> Self {
+        CampaignBuilder::new(snapshot_block, EligibilityRule::minimum_balance(minimum))
+    }
+
+    /// Sets how much is airdropped for each claim.
+    pub fn with_amount(mut self, amount: AmountPolicy) -> Self {
+        self.campaign.amount = amount;
+        self
+    }
+
+    /// Sets the period in which claims are accepted.
+    pub fn with_window(mut self, window: ClaimWindow) -> Self {
+        self.campaign.window = window;
+        self
+    }
+
+    /// Sets the vesting schedule of the airdropped tokens.
+    pub fn with_vesting(mut self, vesting: VestingSchedule) -> Self {
+        self.campaign.vesting = Some(vesting);
+        self
+    }
+
+    /// Sets the amount of tokens minted and transferred to the campaign's budget when it's
+    /// deployed.
+    pub fn with_funding(mut self, funding: Amount) -> Self {
+        self.funding = funding;
+        self
+    }
+
+    /// Sets the network configured in the application's parameters.
+    pub fn with_network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Sets the Gateway used to check eligibility, for example a [`MockGateway`]'s
+    /// configuration.
+    ///
+    /// [`MockGateway`]: super::mock_gateway::MockGateway
+    pub fn with_gateway(mut self, gateway: GatewayConfiguration) -> Self {
+        self.gateway = gateway;
+        self
+    }
+
+    /// Sets the BCS serialized Proof-of-SQL commitments used to verify balance proofs.
+    pub fn with_proof_of_sql_commitments(mut self, commitments: Vec<u8>) -> Self {
+        self.proof_of_sql_commitments = Some(commitments);
+        self
+    }
+
+    /// Returns the [`Campaign`] being built.
+    pub fn campaign(&self) -> &Campaign {
+        &self.campaign
+    }
+
+    /// Builds the [`Campaign`], to be created with an [`Operation::CreateCampaign`].
+    ///
+    /// [`Operation::CreateCampaign`]: crate::Operation::CreateCampaign
+    pub fn build(self) -> Campaign {
+        self.campaign
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Claimers with deterministic Ethereum keys, and builders for the claims they sign.
+
+use alloy_primitives::{keccak256, Address};
+use k256::ecdsa::SigningKey;
+use linera_sdk::{
+    abis::fungible::Account,
+    base::{Amount, ApplicationId},
+};
+
+use crate::{
+    allowlist::AllowlistProof, balance_proof::BalanceProof, signing::sign_claim, AirDropClaim,
+    ApplicationAbi, CampaignId, Operation,
+};
+
+/// A claimer with an Ethereum key derived from a seed, so that the same seed always results in
+/// the same [`Address`].
+#[derive(Clone, Debug)]
+pub struct TestClaimer {
+    signing_key: SigningKey,
+}
+
+impl TestClaimer {
+    /// Creates the [`TestClaimer`] with the `seed`.
+    pub fn new(seed: u64) -> Self {
+        let secret = keccak256(format!("airdrop test claimer {seed}"));
+        let signing_key =
+            SigningKey::from_slice(secret.as_slice()).expect("Hashed seed should be a valid key");
+
+        TestClaimer { signing_key }
+    }
+
+    /// Returns the claimer's [`SigningKey`].
+    pub fn signing_key(&self) -> &SigningKey {
+        &self.signing_key
+    }
+
+    /// Returns the claimer's Ethereum [`Address`].
+    pub fn address(&self) -> Address {
+        Address::from_private_key(&self.signing_key)
+    }
+
+    /// Starts building a claim of the airdrop from a `campaign` of the application with
+    /// `application_id`, paid to the `destination` account.
+    pub fn claim(
+        &self,
+        application_id: ApplicationId<ApplicationAbi>,
+        campaign: CampaignId,
+        destination: Account,
+    ) -> ClaimBuilder<'_> {
+        ClaimBuilder {
+            claimer: self,
+            application_id,
+            campaign,
+            destination,
+            api_token: String::new(),
+            relayer_fee: None,
+            balance_proof: None,
+            allowlist_proof: None,
+        }
+    }
+}
+
+/// A builder of an [`AirDropClaim`], signed by a [`TestClaimer`] when it's built.
+#[derive(Clone, Debug)]
+pub struct ClaimBuilder<'claimer> {
+    claimer: &'claimer TestClaimer,
+    application_id: ApplicationId<ApplicationAbi>,
+    campaign: CampaignId,
+    destination: Account,
+    api_token: String,
+    relayer_fee: Option<Amount>,
+    balance_proof: Option<BalanceProof>,
+    allowlist_proof: Option<AllowlistProof>,
+}
+
+impl ClaimBuilder<'_> {
+    /// Sets the Space-and-Time API token used to check the claimer's eligibility.
+    pub fn with_api_token(mut self, api_token: impl Into<String>) -> Self {
+        self.api_token = api_token.into();
+        self
+    }
+
+    /// Sets the fee paid to the relayer that submits the claim.
+    pub fn with_relayer_fee(mut self, relayer_fee: Amount) -> Self {
+        self.relayer_fee = Some(relayer_fee);
+        self
+    }
+
+    /// Sets the Proof-of-SQL result for the claimer's balance.
+    pub fn with_balance_proof(mut self, balance_proof: BalanceProof) -> Self {
+        self.balance_proof = Some(balance_proof);
+        self
+    }
+
+    /// Sets the proof that the claimer is in the campaign's allowlist.
+    pub fn with_allowlist_proof(mut self, allowlist_proof: AllowlistProof) -> Self {
+        self.allowlist_proof = Some(allowlist_proof);
+        self
+    }
+
+    /// Signs and builds the [`AirDropClaim`].
+    pub fn build(self) -> AirDropClaim {
+        let signature = sign_claim(
+            &self.claimer.signing_key,
+            self.application_id,
+            self.campaign,
+            self.destination,
+            self.relayer_fee,
+        );
+
+        AirDropClaim {
+            campaign: self.campaign,
+            signature: signature.into(),
+            destination: self.destination,
+            api_token: self.api_token,
+            relayer_fee: self.relayer_fee,
+            balance_proof: self.balance_proof,
+            allowlist_proof: self.allowlist_proof,
+        }
+    }
+
+    /// Signs and builds the [`Operation::Claim`] that submits the [`AirDropClaim`].
+    pub fn operation(self) -> Operation {
+        Operation::Claim(self.build())
+    }
+}
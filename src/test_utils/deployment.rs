@@ -0,0 +1,214 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Deployment of the airdrop application and its token to a [`TestValidator`].
+
+use std::{collections::BTreeMap, env};
+
+use async_graphql::InputType;
+use linera_sdk::{
+    abis::fungible::{self, FungibleTokenAbi},
+    base::{AccountOwner, Amount, ApplicationId, Owner},
+    test::{ActiveChain, TestValidator},
+};
+
+use super::CampaignBuilder;
+use crate::{ApplicationAbi, CampaignId, Operation, Parameters};
+
+/// The environment variable with the path of the fungible token application deployed by
+/// [`CampaignBuilder::deploy`].
+pub const FUNGIBLE_PATH_VARIABLE: &str = "LINERA_FUNGIBLE_PATH";
+
+impl CampaignBuilder {
+    /// Deploys a new fungible token and the airdrop application to a new [`TestValidator`], and
+    /// creates the campaign funded with newly minted tokens.
+    ///
+    /// The application's admin is the owner of the airdrop chain, which initially holds the
+    /// minted tokens.
+    ///
+    /// The token is built from the `examples/fungible` directory of a `linera-protocol`
+    /// checkout, at the path in the [`FUNGIBLE_PATH_VARIABLE`] environment variable.
+    ///
+    /// # Panics
+    ///
+    /// If the [`FUNGIBLE_PATH_VARIABLE`] environment variable isn't set.
+    pub async fn deploy(self) -> TestAirdrop {
+        let fungible_path = env::var_os(FUNGIBLE_PATH_VARIABLE).unwrap_or_else(|| {
+            panic!(
+                "`{FUNGIBLE_PATH_VARIABLE}` should be set to the `examples/fungible` directory \
+                of a `linera-protocol` checkout"
+            )
+        });
+
+        let validator = TestValidator::new().await;
+        let mut chain = validator.new_chain().await;
+        let admin = Owner::from(chain.public_key());
+
+        let fungible_bytecode_id = chain.publish_bytecodes_in(fungible_path).await;
+        let token_id = chain
+            .create_application(
+                fungible_bytecode_id,
+                fungible::Parameters {
+                    ticker_symbol: "TOK".to_owned(),
+                },
+                fungible::InitialState {
+                    accounts: BTreeMap::from([(AccountOwner::User(admin), self.funding)]),
+                },
+                vec![],
+            )
+            .await;
+
+        let bytecode_id = chain.publish_bytecodes_in(env!("CARGO_MANIFEST_DIR")).await;
+        let application_id = chain
+            .create_application(
+                bytecode_id,
+                Parameters {
+                    token_id,
+                    admin,
+                    network: self.network,
+                    proof_of_sql_commitments: self.proof_of_sql_commitments,
+                    gateway: self.gateway,
+                },
+                (),
+                vec![token_id.forget_abi()],
+            )
+            .await;
+
+        chain
+            .add_block(|block| {
+                block.with_operation(application_id, Operation::CreateCampaign(self.campaign));
+
+                if self.funding > Amount::ZERO {
+                    block.with_operation(
+                        application_id,
+                        Operation::Fund {
+                            campaign: TestAirdrop::CAMPAIGN,
+                            amount: self.funding,
+                        },
+                    );
+                }
+            })
+            .await;
+
+        TestAirdrop {
+            validator,
+            chain,
+            token_id,
+            application_id,
+        }
+    }
+}
+
+/// An airdrop application deployed by [`CampaignBuilder::deploy`], with a single funded
+/// campaign.
+pub struct TestAirdrop {
+    validator: TestValidator,
+    chain: ActiveChain,
+    token_id: ApplicationId<FungibleTokenAbi>,
+    application_id: ApplicationId<ApplicationAbi>,
+}
+
+impl TestAirdrop {
+    /// The campaign created when the application is deployed.
+    pub const CAMPAIGN: CampaignId = CampaignId(0);
+
+    /// Returns the [`TestValidator`] running the application.
+    pub fn validator(&self) -> &TestValidator {
+        &self.validator
+    }
+
+    /// Returns the chain where the application was created, which handles the claims.
+    pub fn chain(&self) -> &ActiveChain {
+        &self.chain
+    }
+
+    /// Returns the ID of the airdropped fungible token.
+    pub fn token_id(&self) -> ApplicationId<FungibleTokenAbi> {
+        self.token_id
+    }
+
+    /// Returns the ID of the airdrop application.
+    pub fn application_id(&self) -> ApplicationId<ApplicationAbi> {
+        self.application_id
+    }
+
+    /// Returns the application's account on its chain, which holds the campaigns' budgets.
+    pub fn account(&self) -> fungible::Account {
+        fungible::Account {
+            chain_id: self.chain.id(),
+            owner: AccountOwner::Application(self.application_id.forget_abi()),
+        }
+    }
+
+    /// Creates a new chain that can submit claims, returning it with the account of its owner
+    /// to receive the airdropped tokens.
+    pub async fn new_claimer_chain(&self) -> (ActiveChain, fungible::Account) {
+        let chain = self.validator.new_chain().await;
+        let account = fungible::Account {
+            chain_id: chain.id(),
+            owner: AccountOwner::from(chain.public_key()),
+        };
+
+        chain.register_application(self.application_id).await;
+
+        (chain, account)
+    }
+
+    /// Queries the token balance of an `owner` on a `chain`, which is zero if the owner has no
+    /// account.
+    pub async fn balance(&self, chain: &ActiveChain, owner: AccountOwner) -> Amount {
+        let owner = owner.to_value();
+        let query = format!("query {{ accounts {{ entry(key: {owner}) {{ value }} }} }}");
+
+        let response = chain.graphql_query(self.token_id, query).await;
+
+        response
+            .pointer("/accounts/entry/value")
+            .and_then(|balance| balance.as_str())
+            .map(|balance| {
+                balance
+                    .parse()
+                    .expect("Failed to parse account balance amount")
+            })
+            .unwrap_or(Amount::ZERO)
+    }
+
+    /// Asserts that the token balance of an `owner` on a `chain` is the `expected` amount.
+    pub async fn assert_balance(&self, chain: &ActiveChain, owner: AccountOwner, expected: Amount) {
+        let balance = self.balance(chain, owner).await;
+
+        assert_eq!(
+            balance,
+            expected,
+            "Unexpected token balance of {owner} on chain {}",
+            chain.id()
+        );
+    }
+
+    /// Asserts that the application's account holds the `expected` amount of tokens.
+    pub async fn assert_application_balance(&self, expected: Amount) {
+        self.assert_balance(&self.chain, self.account().owner, expected)
+            .await;
+    }
+
+    /// Queries the reasons of the claims rejected by the creator chain, in the order they were
+    /// recorded.
+    pub async fn rejection_reasons(&self) -> Vec<String> {
+        let response = self
+            .chain
+            .graphql_query(self.application_id, "query { rejectedClaims { reason } }")
+            .await;
+
+        response["rejectedClaims"]
+            .as_array()
+            .expect("Failed to read rejected claims")
+            .iter()
+            .map(|rejected_claim| {
+                rejected_claim["reason"]
+                    .as_str()
+                    .expect("Failed to read rejection reason")
+                    .to_owned()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Builders of the Space-and-Time Gateway's responses, to mock its queries in service tests.
+
+use std::fmt::Display;
+
+use linera_sdk::{http, serde_json, service::MockServiceRuntime, Service};
+
+use crate::gateway::GatewayConfiguration;
+
+/// Creates a successful response with a single row where the `column` has the `value`.
+///
+/// The value is sent as a string, like the Gateway does for large numbers.
+pub fn single_row_response(column: &str, value: impl Display) -> http::Response {
+    let rows = serde_json::json!([{ column: value.to_string() }]);
+
+    http::Response::ok(rows.to_string().as_bytes())
+}
+
+/// Creates a successful response to a balance query, with the balance in the default
+/// `BALANCE` column.
+pub fn balance_response(balance: impl Display) -> http::Response {
+    single_row_response("BALANCE", balance)
+}
+
+/// Creates a successful response without any rows, returned when the queried address has no
+/// recorded balance or activity.
+pub fn empty_response() -> http::Response {
+    http::Response::ok(b"[]")
+}
+
+/// Creates the response to a query with an API token that the Gateway doesn't accept.
+pub fn unauthorized_response() -> http::Response {
+    http::Response::unauthorized()
+}
+
+/// Configures a [`MockServiceRuntime`] to return the `response` when the `gateway` is queried
+/// with the `sql_query` and the `api_token`.
+pub fn expect_gateway_query<Application: Service>(
+    runtime: &mut MockServiceRuntime<Application>,
+    gateway: &GatewayConfiguration,
+    sql_query: &str,
+    api_token: &str,
+    response: http::Response,
+) {
+    let expected_query = serde_json::json!({ "sqlText": sql_query }).to_string();
+
+    runtime.add_expected_http_request(
+        http::Request::post(gateway.url.clone(), expected_query.as_bytes())
+            .with_header("Content-Type", b"application/json")
+            .with_header("Authorization", format!("Bearer {api_token}").as_bytes()),
+        response,
+    );
+}
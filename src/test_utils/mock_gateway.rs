@@ -0,0 +1,154 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A local stand-in for the Space-and-Time Gateway's `/v1/sql` endpoint, backed by a fixture
+//! table of balances.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+    thread,
+};
+
+use alloy_primitives::{Address, U256};
+use linera_sdk::serde_json;
+
+use crate::gateway::GatewayConfiguration;
+
+/// The balance of an address recorded at a block.
+#[derive(Clone, Copy, Debug)]
+pub struct BalanceRecord {
+    pub address: Address,
+    pub block: u64,
+    pub balance: U256,
+}
+
+/// A mock Gateway server running in a background thread.
+///
+/// It answers the queries rendered from the default [`GatewayConfiguration`] query template with
+/// the latest [`BalanceRecord`] of the queried address up to the snapshot block.
+pub struct MockGateway {
+    url: String,
+}
+
+impl MockGateway {
+    /// Starts a new [`MockGateway`] that accepts requests authorized with the `api_token` and
+    /// answers them using the `records`.
+    pub fn start(api_token: &str, records: impl IntoIterator<Item = BalanceRecord>) -> Self {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").expect("Failed to bind mock Gateway to a local port");
+        let address = listener
+            .local_addr()
+            .expect("Failed to read mock Gateway's local address");
+
+        let records = Arc::new(records.into_iter().collect::<Vec<_>>());
+        let authorization = format!("Bearer {api_token}");
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle_request(stream, &authorization, &records);
+            }
+        });
+
+        MockGateway {
+            url: format!("http://{address}/v1/sql"),
+        }
+    }
+
+    /// Returns the [`GatewayConfiguration`] to use this mock Gateway.
+    pub fn configuration(&self) -> GatewayConfiguration {
+        GatewayConfiguration {
+            url: self.url.clone(),
+            ..GatewayConfiguration::default()
+        }
+    }
+}
+
+/// Reads an HTTP request from the `stream` and writes back the response.
+fn handle_request(mut stream: TcpStream, authorization: &str, records: &[BalanceRecord]) {
+    let mut reader = BufReader::new(&mut stream);
+    let mut content_length = 0;
+    let mut is_authorized = false;
+
+    loop {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+            return;
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+
+            if name.eq_ignore_ascii_case("Content-Length") {
+                content_length = value.parse().unwrap_or(0);
+            } else if name.eq_ignore_ascii_case("Authorization") {
+                is_authorized = value == authorization;
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+
+    if reader.read_exact(&mut body).is_err() {
+        return;
+    }
+
+    let (status, response_body) = if !is_authorized {
+        ("401 Unauthorized", String::new())
+    } else {
+        match answer_query(&body, records) {
+            Some(rows) => ("200 OK", rows),
+            None => ("400 Bad Request", String::new()),
+        }
+    };
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {status}\r\n\
+        Content-Type: application/json\r\n\
+        Content-Length: {}\r\n\
+        Connection: close\r\n\
+        \r\n\
+        {response_body}",
+        response_body.len()
+    );
+}
+
+/// Answers the balance query in a request `body`, returning the JSON result rows.
+fn answer_query(body: &[u8], records: &[BalanceRecord]) -> Option<String> {
+    let request = serde_json::from_slice::<serde_json::Value>(body).ok()?;
+    let sql_text = request.get("sqlText")?.as_str()?;
+
+    let address_start = sql_text.find("WALLET_ADDRESS = '")? + "WALLET_ADDRESS = '".len();
+    let address_length = sql_text[address_start..].find('\'')?;
+    let address = sql_text[address_start..][..address_length]
+        .parse::<Address>()
+        .ok()?;
+
+    let block_start = sql_text.find("BLOCK_NUMBER <= ")? + "BLOCK_NUMBER <= ".len();
+    let snapshot_block = sql_text[block_start..]
+        .split(|character: char| !character.is_ascii_digit())
+        .next()?
+        .parse::<u64>()
+        .ok()?;
+
+    let latest_record = records
+        .iter()
+        .filter(|record| record.address == address && record.block <= snapshot_block)
+        .max_by_key(|record| record.block);
+
+    let rows = match latest_record {
+        Some(record) => serde_json::json!([{ "BALANCE": record.balance.to_string() }]),
+        None => serde_json::json!([]),
+    };
+
+    Some(rows.to_string())
+}
@@ -0,0 +1,55 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A kit to test the airdrop application and the applications that integrate with it.
+//!
+//! The helpers that build claims, campaigns, Gateway responses and balance proofs are available
+//! with the `test` feature. Deploying the applications to a [`TestValidator`] also requires the
+//! `integration-test` feature, which enables the Wasm runtime used by the validator.
+//!
+//! [`TestValidator`]: linera_sdk::test::TestValidator
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod balance_proofs;
+mod campaign;
+mod claimer;
+#[cfg(all(feature = "integration-test", not(target_arch = "wasm32")))]
+mod deployment;
+pub mod gateway_responses;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mock_gateway;
+
+use linera_sdk::base::{ApplicationId, BlockHeight, BytecodeId, ChainId, CryptoHash, MessageId};
+
+#[cfg(all(feature = "integration-test", not(target_arch = "wasm32")))]
+pub use self::deployment::{TestAirdrop, FUNGIBLE_PATH_VARIABLE};
+pub use self::{
+    campaign::CampaignBuilder,
+    claimer::{ClaimBuilder, TestClaimer},
+};
+pub use crate::signing::sign_claim;
+
+/// Creates a dummy [`ApplicationId`] to use as the Fungible Token for testing.
+pub fn create_dummy_token_id<Abi>() -> ApplicationId<Abi> {
+    create_dummy_application_id("fungible token", 0)
+}
+
+/// Creates a dummy [`ApplicationId`] to use for testing.
+pub fn create_dummy_application_id<Abi>(name: &str, index: u32) -> ApplicationId<Abi> {
+    let bytecode_id = BytecodeId::new(
+        CryptoHash::test_hash(format!("{name} contract")),
+        CryptoHash::test_hash(format!("{name} service")),
+    );
+
+    let creation = MessageId {
+        chain_id: ChainId(CryptoHash::test_hash("chain")),
+        height: BlockHeight::ZERO,
+        index,
+    };
+
+    ApplicationId {
+        bytecode_id,
+        creation,
+    }
+    .with_abi()
+}
@@ -0,0 +1,310 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validation of the application's [`Parameters`] and of its [`Campaign`]s, shared by the
+//! contract and the native deployment helper.
+
+use alloy_primitives::U256;
+use linera_sdk::base::Amount;
+
+use crate::{
+    balance_proof, gateway::QueryTemplateError, network::Network, AmountPolicy, Campaign,
+    Criterion, EligibilityRule, HoldingRule, Parameters,
+};
+
+impl Parameters {
+    /// Checks that the [`Parameters`] are within their valid ranges.
+    ///
+    /// The token application can only be checked when the application is instantiated.
+    pub fn validate(&self) -> Result<(), ParametersError> {
+        self.network
+            .validate()
+            .map_err(ParametersError::InvalidNetwork)?;
+
+        if let Some(commitments) = &self.proof_of_sql_commitments {
+            balance_proof::deserialize_commitments(commitments)
+                .map_err(|_| ParametersError::InvalidProofOfSqlCommitments)?;
+        }
+
+        if !self.gateway.url.starts_with("https://") && !self.gateway.url.starts_with("http://") {
+            return Err(ParametersError::InvalidGatewayUrl);
+        }
+        if self.gateway.balance_column.is_empty() {
+            return Err(ParametersError::MissingBalanceColumn);
+        }
+        for (name, template, placeholders) in self.gateway.query_templates() {
+            template
+                .validate(placeholders)
+                .map_err(|error| ParametersError::InvalidQueryTemplate(name, error))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Errors that make [`Parameters`] invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParametersError {
+    InvalidNetwork(NetworkError),
+    InvalidProofOfSqlCommitments,
+    InvalidGatewayUrl,
+    MissingBalanceColumn,
+    InvalidQueryTemplate(&'static str, QueryTemplateError),
+}
+
+impl std::fmt::Display for ParametersError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let reason = match self {
+            ParametersError::InvalidNetwork(error) => return write!(formatter, "{error}"),
+            ParametersError::InvalidProofOfSqlCommitments => {
+                "Proof-of-SQL commitments could not be deserialized"
+            }
+            ParametersError::InvalidGatewayUrl => "Gateway URL must be an HTTP or HTTPS URL",
+            ParametersError::MissingBalanceColumn => "Gateway balance column must not be empty",
+            ParametersError::InvalidQueryTemplate(name, error) => {
+                return write!(formatter, "Gateway's `{name}` is invalid: {error}");
+            }
+        };
+
+        formatter.write_str(reason)
+    }
+}
+
+impl std::error::Error for ParametersError {}
+
+impl Network {
+    /// Checks that a [`Network::Other`] has a schema that can be used in SQL queries and a
+    /// non-zero chain ID.
+    pub fn validate(&self) -> Result<(), NetworkError> {
+        if let Network::Other { schema, chain_id } = self {
+            if schema.is_empty()
+                || !schema
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric() || character == '_')
+            {
+                return Err(NetworkError::InvalidSchema);
+            }
+            if *chain_id == 0 {
+                return Err(NetworkError::InvalidChainId);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Errors that make a [`Network`] invalid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NetworkError {
+    InvalidSchema,
+    InvalidChainId,
+}
+
+impl std::fmt::Display for NetworkError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let reason = match self {
+            NetworkError::InvalidSchema => {
+                "Network schema must be a non-empty alphanumeric identifier"
+            }
+            NetworkError::InvalidChainId => "Network chain ID must not be zero",
+        };
+
+        formatter.write_str(reason)
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+impl Campaign {
+    /// Checks that the [`Campaign`]'s configuration is within its valid ranges.
+    ///
+    /// Snapshot blocks can't be checked against the network's latest block on-chain, so that is
+    /// left to the deployment helper.
+    pub fn validate(&self) -> Result<(), CampaignError> {
+        let rule = &self.eligibility.rule;
+        let is_allowlist = matches!(
+            rule,
+            EligibilityRule::Criterion(Criterion::Allowlisted { .. })
+        );
+
+        if is_allowlist != (self.amount == AmountPolicy::Allowlisted) {
+            return Err(CampaignError::MisplacedAllowlist);
+        }
+        if self.amount == AmountPolicy::Fixed(Amount::ZERO) {
+            return Err(CampaignError::ZeroAmount);
+        }
+        if self.window.start >= self.window.end {
+            return Err(CampaignError::EmptyClaimWindow);
+        }
+
+        if let Some(vesting) = &self.vesting {
+            if vesting.release_interval.as_micros() == 0 || vesting.cliff > vesting.duration {
+                return Err(CampaignError::InvalidVestingSchedule);
+            }
+        }
+
+        if rule.has_linera_criteria() {
+            if rule.any_criterion(&|criterion| !criterion.is_linera()) {
+                return Err(CampaignError::MixedLineraRule);
+            }
+            if self.vesting.is_some() {
+                return Err(CampaignError::VestedLineraCampaign);
+            }
+        }
+
+        validate_rule(rule, self.eligibility.snapshot_block, true)
+    }
+
+    /// Returns the snapshot blocks of the [`Campaign`] on each network, with `None` for the
+    /// network configured in the [`Parameters`].
+    pub fn snapshot_blocks(&self) -> Vec<(Option<&Network>, u64)> {
+        let mut snapshot_blocks = vec![(None, self.eligibility.snapshot_block)];
+        collect_snapshot_blocks(&self.eligibility.rule, &mut snapshot_blocks);
+        snapshot_blocks
+    }
+}
+
+/// Checks that a `rule` and its sub-rules are within their valid ranges.
+///
+/// Allowlists are only valid as the campaign's whole rule, at its `is_root`.
+fn validate_rule(
+    rule: &EligibilityRule,
+    snapshot_block: u64,
+    is_root: bool,
+) -> Result<(), CampaignError> {
+    match rule {
+        EligibilityRule::All(rules) | EligibilityRule::Any(rules) => rules
+            .iter()
+            .try_for_each(|rule| validate_rule(rule, snapshot_block, false)),
+        EligibilityRule::Not(rule) => validate_rule(rule, snapshot_block, false),
+        EligibilityRule::Criterion(criterion) => match criterion {
+            Criterion::Balance { minimum, holding } => {
+                if *minimum == U256::ZERO {
+                    return Err(CampaignError::ZeroMinimumBalance);
+                }
+                match holding {
+                    HoldingRule::AtSnapshot => Ok(()),
+                    HoldingRule::Continuous { start_block }
+                    | HoldingRule::TimeWeightedAverage { start_block } => {
+                        if *start_block > snapshot_block {
+                            return Err(CampaignError::HoldingPeriodAfterSnapshot);
+                        }
+                        Ok(())
+                    }
+                }
+            }
+            Criterion::TokenBalance { minimum, .. } => {
+                if *minimum == U256::ZERO {
+                    return Err(CampaignError::ZeroMinimumBalance);
+                }
+                Ok(())
+            }
+            Criterion::CombinedBalance { minimum, snapshots } => {
+                if *minimum == U256::ZERO {
+                    return Err(CampaignError::ZeroMinimumBalance);
+                }
+                if snapshots.is_empty() {
+                    return Err(CampaignError::MissingSnapshots);
+                }
+                snapshots.iter().try_for_each(|snapshot| {
+                    snapshot
+                        .network
+                        .validate()
+                        .map_err(CampaignError::InvalidSnapshotNetwork)
+                })
+            }
+            Criterion::Allowlisted { .. } if !is_root => Err(CampaignError::MisplacedAllowlist),
+            Criterion::LineraMember { members } => {
+                if members.is_empty() {
+                    return Err(CampaignError::MissingLineraMembers);
+                }
+                Ok(())
+            }
+            Criterion::LineraTokenBalance { minimum, .. } => {
+                if *minimum == Amount::ZERO {
+                    return Err(CampaignError::ZeroMinimumBalance);
+                }
+                Ok(())
+            }
+            Criterion::Activity(_)
+            | Criterion::Denylisted
+            | Criterion::Allowlisted { .. }
+            | Criterion::LineraApplicationMember { .. } => Ok(()),
+        },
+    }
+}
+
+/// Adds the snapshot blocks of the [`Criterion::CombinedBalance`]s in a `rule` to the
+/// `snapshot_blocks`.
+fn collect_snapshot_blocks<'rule>(
+    rule: &'rule EligibilityRule,
+    snapshot_blocks: &mut Vec<(Option<&'rule Network>, u64)>,
+) {
+    match rule {
+        EligibilityRule::All(rules) | EligibilityRule::Any(rules) => {
+            for rule in rules {
+                collect_snapshot_blocks(rule, snapshot_blocks);
+            }
+        }
+        EligibilityRule::Not(rule) => collect_snapshot_blocks(rule, snapshot_blocks),
+        EligibilityRule::Criterion(Criterion::CombinedBalance { snapshots, .. }) => {
+            snapshot_blocks.extend(
+                snapshots
+                    .iter()
+                    .map(|snapshot| (Some(&snapshot.network), snapshot.block)),
+            );
+        }
+        EligibilityRule::Criterion(_) => {}
+    }
+}
+
+/// Errors that make a [`Campaign`] invalid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CampaignError {
+    MisplacedAllowlist,
+    ZeroAmount,
+    EmptyClaimWindow,
+    InvalidVestingSchedule,
+    ZeroMinimumBalance,
+    HoldingPeriodAfterSnapshot,
+    MissingSnapshots,
+    InvalidSnapshotNetwork(NetworkError),
+    MixedLineraRule,
+    MissingLineraMembers,
+    VestedLineraCampaign,
+}
+
+impl std::fmt::Display for CampaignError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let reason = match self {
+            CampaignError::MisplacedAllowlist => {
+                "Allowlisted amounts require a single allowlist eligibility rule, and vice versa"
+            }
+            CampaignError::ZeroAmount => "Campaign must airdrop a non-zero amount",
+            CampaignError::EmptyClaimWindow => "Campaign's claim window must end after it starts",
+            CampaignError::InvalidVestingSchedule => {
+                "Vesting schedule must have a non-zero release interval and a cliff within its \
+                duration"
+            }
+            CampaignError::ZeroMinimumBalance => "Balance criteria must have a non-zero minimum",
+            CampaignError::HoldingPeriodAfterSnapshot => {
+                "Holding period must start at or before the snapshot block"
+            }
+            CampaignError::MissingSnapshots => "Combined balance criteria must have snapshots",
+            CampaignError::InvalidSnapshotNetwork(error) => {
+                return write!(formatter, "Combined balance snapshot is invalid: {error}");
+            }
+            CampaignError::MixedLineraRule => {
+                "Criteria on Linera identities can't be combined with Ethereum criteria"
+            }
+            CampaignError::MissingLineraMembers => "Linera member criteria must have members",
+            CampaignError::VestedLineraCampaign => {
+                "Campaigns with Linera members can't vest their airdrops"
+            }
+        };
+
+        formatter.write_str(reason)
+    }
+}
+
+impl std::error::Error for CampaignError {}
@@ -0,0 +1,95 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+//! Tests of the Merkle allowlists against OpenZeppelin's `StandardMerkleTree`.
+
+use airdrop_demo::{
+    allowlist::{AllowlistProof, AllowlistTree},
+    ethereum::EthereumHash,
+};
+use alloy_primitives::{address, b256, Address, B256};
+use linera_sdk::base::Amount;
+
+/// The first address of the example in OpenZeppelin's `merkle-tree` README.
+const FIRST_ADDRESS: Address = address!("1111111111111111111111111111111111111111");
+
+/// The second address of the example in OpenZeppelin's `merkle-tree` README.
+const SECOND_ADDRESS: Address = address!("2222222222222222222222222222222222222222");
+
+/// The root that `StandardMerkleTree.of(values, ["address", "uint256"])` returns for the example
+/// in OpenZeppelin's `merkle-tree` README.
+const OPENZEPPELIN_ROOT: B256 =
+    b256!("d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77");
+
+/// The proof that `StandardMerkleTree` returns for the [`FIRST_ADDRESS`] in the example, which
+/// is the leaf of the [`SECOND_ADDRESS`].
+const OPENZEPPELIN_FIRST_PROOF: B256 =
+    b256!("b92c48e9d7abe27fd8dfd6b5dfdbfb1c9a463f80c712b66f3a5180a090cccafc");
+
+/// The leaf of the [`FIRST_ADDRESS`] in the example, which is the proof of the
+/// [`SECOND_ADDRESS`].
+const OPENZEPPELIN_SECOND_PROOF: B256 =
+    b256!("eb02c421cfa48976e66dfb29120745909ea3a0f843456c263cf8f1253483e283");
+
+/// Tests if the tree built for OpenZeppelin's example has the same root and layout as a
+/// `StandardMerkleTree`.
+#[test]
+fn tree_matches_openzeppelin_fixture() {
+    let tree = AllowlistTree::new(&openzeppelin_entries());
+
+    assert_eq!(tree.root(), OPENZEPPELIN_ROOT);
+    assert_eq!(
+        tree.nodes(),
+        [
+            OPENZEPPELIN_ROOT,
+            OPENZEPPELIN_SECOND_PROOF,
+            OPENZEPPELIN_FIRST_PROOF
+        ]
+    );
+}
+
+/// Tests if the proofs of the entries in OpenZeppelin's example are the ones computed by a
+/// `StandardMerkleTree`, and if they verify against its root.
+#[test]
+fn proofs_match_openzeppelin_fixture() {
+    let entries = openzeppelin_entries();
+    let tree = AllowlistTree::new(&entries);
+    let expected_proofs = [OPENZEPPELIN_FIRST_PROOF, OPENZEPPELIN_SECOND_PROOF];
+
+    for ((address, amount, leaf_index), expected_proof) in
+        tree.entries().iter().zip(expected_proofs)
+    {
+        let proof = tree.proof(*leaf_index);
+
+        assert_eq!(
+            proof,
+            AllowlistProof {
+                amount: *amount,
+                proof: vec![EthereumHash(expected_proof)],
+            }
+        );
+        assert!(proof.verify(OPENZEPPELIN_ROOT, *address));
+    }
+}
+
+/// Tests if a proof from OpenZeppelin's example doesn't verify with a different amount.
+#[test]
+fn openzeppelin_proof_is_bound_to_amount() {
+    let proof = AllowlistProof {
+        amount: Amount::from_tokens(6),
+        proof: vec![EthereumHash(OPENZEPPELIN_FIRST_PROOF)],
+    };
+
+    assert!(!proof.verify(OPENZEPPELIN_ROOT, FIRST_ADDRESS));
+}
+
+/// Returns the entries of the example in OpenZeppelin's `merkle-tree` README, with 5 and 2.5
+/// tokens.
+fn openzeppelin_entries() -> [(Address, Amount); 2] {
+    [
+        (FIRST_ADDRESS, Amount::from_tokens(5)),
+        (
+            SECOND_ADDRESS,
+            Amount::from_attos(2_500_000_000_000_000_000),
+        ),
+    ]
+}
@@ -0,0 +1,138 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+//! Tests of the verification of Proof-of-SQL balance proofs against committed tables.
+
+use airdrop_demo::{
+    balance_proof::{BalanceProofError, ProvenBalance},
+    network::Network,
+    test_utils::{balance_proofs::MockProver, mock_gateway::BalanceRecord},
+};
+use alloy_primitives::{address, Address, U256};
+
+/// The address whose balance is proven in the tests.
+const CLAIMER: Address = address!("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+
+/// Another address with balance records in the table.
+const OTHER_ADDRESS: Address = address!("fB6916095ca1df60bB79Ce92cE3Ea74c37c5d359");
+
+/// The snapshot block used in the tests' balance queries.
+const SNAPSHOT_BLOCK: u64 = 100;
+
+/// Tests if a proof of the balance query is verified, and if the balance of the latest record up
+/// to the snapshot block is returned.
+#[test]
+fn verifies_balance_proof() {
+    let prover = create_prover();
+    let proof = prover.prove(CLAIMER, SNAPSHOT_BLOCK);
+
+    assert_eq!(
+        proof.verify(
+            &Network::Ethereum,
+            CLAIMER,
+            &prover.serialized_commitments()
+        ),
+        Ok(ProvenBalance {
+            snapshot_block: SNAPSHOT_BLOCK,
+            balance: U256::from(2_000),
+        })
+    );
+}
+
+/// Tests if a proof of an address without records is verified as a zero balance.
+#[test]
+fn verifies_balance_proof_without_records() {
+    let prover = create_prover();
+    let address = Address::random();
+    let proof = prover.prove(address, SNAPSHOT_BLOCK);
+
+    assert_eq!(
+        proof.verify(
+            &Network::Ethereum,
+            address,
+            &prover.serialized_commitments()
+        ),
+        Ok(ProvenBalance {
+            snapshot_block: SNAPSHOT_BLOCK,
+            balance: U256::ZERO,
+        })
+    );
+}
+
+/// Tests if a proof of another address's balance is rejected.
+#[test]
+fn rejects_balance_proof_of_other_address() {
+    let prover = create_prover();
+    let proof = prover.prove(OTHER_ADDRESS, SNAPSHOT_BLOCK);
+
+    assert_eq!(
+        proof.verify(
+            &Network::Ethereum,
+            CLAIMER,
+            &prover.serialized_commitments()
+        ),
+        Err(BalanceProofError::VerificationFailed)
+    );
+}
+
+/// Tests if a proof is rejected when it claims a snapshot block other than the one it was
+/// proven at.
+#[test]
+fn rejects_balance_proof_with_other_snapshot_block() {
+    let prover = create_prover();
+    let mut proof = prover.prove(CLAIMER, SNAPSHOT_BLOCK);
+
+    proof.snapshot_block = SNAPSHOT_BLOCK + 20;
+
+    assert_eq!(
+        proof.verify(
+            &Network::Ethereum,
+            CLAIMER,
+            &prover.serialized_commitments()
+        ),
+        Err(BalanceProofError::VerificationFailed)
+    );
+}
+
+/// Tests if a proof is rejected when it is verified against the commitments of another table.
+#[test]
+fn rejects_balance_proof_for_other_table() {
+    let prover = create_prover();
+    let other_prover = MockProver::new(
+        Network::Ethereum,
+        [BalanceRecord {
+            address: CLAIMER,
+            block: 90,
+            balance: U256::from(9_000),
+        }],
+    );
+    let proof = other_prover.prove(CLAIMER, SNAPSHOT_BLOCK);
+
+    assert_eq!(
+        proof.verify(
+            &Network::Ethereum,
+            CLAIMER,
+            &prover.serialized_commitments()
+        ),
+        Err(BalanceProofError::VerificationFailed)
+    );
+}
+
+/// Creates a [`MockProver`] for a table with records of the [`CLAIMER`] before and after the
+/// [`SNAPSHOT_BLOCK`], and of the [`OTHER_ADDRESS`].
+fn create_prover() -> MockProver {
+    let record = |address, block, balance: u64| BalanceRecord {
+        address,
+        block,
+        balance: U256::from(balance),
+    };
+
+    MockProver::new(
+        Network::Ethereum,
+        [
+            record(CLAIMER, 90, 1_000),
+            record(OTHER_ADDRESS, 92, 7_000),
+            record(CLAIMER, 95, 2_000),
+            record(CLAIMER, 110, 5_000),
+        ],
+    )
+}
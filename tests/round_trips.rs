@@ -0,0 +1,290 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+//! Property-based tests of the GraphQL types, the claims' signatures and their BCS encoding.
+
+use airdrop_demo::{
+    allowlist::AllowlistProof,
+    ethereum::{EthereumAddress, EthereumHash, EthereumSignature},
+    graphql::AirDropIdInput,
+    network::Network,
+    signing::{recover_claim_signer, sign_claim},
+    test_utils::create_dummy_application_id,
+    AirDropClaim, AirDropId, ApplicationAbi, CampaignId, Operation,
+};
+use alloy_primitives::{Address, B256};
+use async_graphql::{InputType, ScalarType};
+use k256::ecdsa::SigningKey;
+use linera_sdk::{
+    abis::fungible::Account,
+    base::{AccountOwner, Amount, ApplicationId, ChainId, CryptoHash, Owner},
+    bcs,
+};
+use proptest::{collection::vec, option, prelude::*};
+
+proptest! {
+    /// Tests if an [`AirDropId`] is parsed back from its address with the EIP-55 checksum or with
+    /// the EIP-1191 checksum of the configured network, which is how the service displays it.
+    #[test]
+    fn air_drop_id_round_trips_through_graphql(
+        id in air_drop_id(),
+        network in network(),
+        has_network_checksum in any::<bool>(),
+    ) {
+        let checksum_chain_id = has_network_checksum.then(|| network.chain_id());
+        let input = AirDropIdInput {
+            campaign: id.campaign(),
+            address: id.external_address().to_checksum(checksum_chain_id),
+        };
+
+        prop_assert_eq!(input.into_airdrop_id(&network).ok(), Some(id));
+    }
+
+    /// Tests if an [`AirDropClaim`] is parsed back from its GraphQL value.
+    #[test]
+    fn air_drop_claim_round_trips_through_graphql(claim in air_drop_claim()) {
+        let parsed = <AirDropClaim as InputType>::parse(Some(InputType::to_value(&claim)));
+
+        prop_assert_eq!(parsed.ok(), Some(claim));
+    }
+
+    /// Tests if an [`AllowlistProof`] is parsed back from its GraphQL value.
+    #[test]
+    fn allowlist_proof_round_trips_through_graphql(proof in allowlist_proof()) {
+        let parsed = <AllowlistProof as InputType>::parse(Some(InputType::to_value(&proof)));
+
+        prop_assert_eq!(parsed.ok(), Some(proof));
+    }
+
+    /// Tests if an [`Operation::Claim`] is deserialized back from its BCS bytes.
+    #[test]
+    fn claim_operation_round_trips_through_bcs(claim in air_drop_claim()) {
+        let operation = Operation::Claim(claim);
+
+        let bytes = bcs::to_bytes(&operation).expect("`Operation` should be serializable");
+        let deserialized = bcs::from_bytes::<Operation>(&bytes);
+
+        prop_assert_eq!(deserialized.ok(), Some(operation));
+    }
+
+    /// Tests if the signer of a claim is recovered from its signature.
+    #[test]
+    fn recovers_claim_signer(
+        signing_key in signing_key(),
+        application_id in application_id(),
+        campaign in campaign_id(),
+        destination in account(),
+        relayer_fee in option::of(amount()),
+    ) {
+        let signature = sign_claim(&signing_key, application_id, campaign, destination, relayer_fee);
+
+        let signer =
+            recover_claim_signer(&signature, application_id, campaign, &destination, relayer_fee);
+
+        prop_assert_eq!(signer.ok(), Some(Address::from_private_key(&signing_key)));
+    }
+
+    /// Tests if a signature can't be reused to claim for a different destination.
+    #[test]
+    fn signature_does_not_authorize_other_destinations(
+        signing_key in signing_key(),
+        application_id in application_id(),
+        campaign in campaign_id(),
+        destination in account(),
+        other_destination in account(),
+    ) {
+        prop_assume!(destination != other_destination);
+
+        let signature = sign_claim(&signing_key, application_id, campaign, destination, None);
+
+        let signer =
+            recover_claim_signer(&signature, application_id, campaign, &other_destination, None);
+
+        prop_assert_ne!(signer.ok(), Some(Address::from_private_key(&signing_key)));
+    }
+
+    /// Tests if an [`AirDropClaim`] without one of its required fields is rejected.
+    #[test]
+    fn rejects_claim_without_required_field(
+        claim in air_drop_claim(),
+        field in prop::sample::select(vec!["campaign", "signature", "destination"]),
+    ) {
+        let async_graphql::Value::Object(mut fields) = InputType::to_value(&claim) else {
+            panic!("`AirDropClaim` should be represented as an object");
+        };
+
+        fields.swap_remove(field);
+
+        let parsed = <AirDropClaim as InputType>::parse(Some(async_graphql::Value::Object(fields)));
+
+        prop_assert!(parsed.is_err());
+    }
+
+    /// Tests if an [`EthereumAddress`] is parsed back from its GraphQL value, and from its
+    /// lowercase and EIP-55 checksummed representations.
+    #[test]
+    fn ethereum_address_round_trips_through_graphql(address in any::<[u8; 20]>()) {
+        let address = EthereumAddress(Address::from(address));
+        let representations = [
+            ScalarType::to_value(&address),
+            async_graphql::Value::String(format!("{:x}", address.0)),
+            async_graphql::Value::String(address.0.to_checksum(None)),
+        ];
+
+        for representation in representations {
+            let parsed = <EthereumAddress as ScalarType>::parse(representation);
+            prop_assert_eq!(parsed.ok(), Some(address));
+        }
+    }
+
+    /// Tests if an [`EthereumAddress`] with a letter in the wrong case is rejected.
+    #[test]
+    fn rejects_ethereum_address_with_invalid_checksum(
+        address in any::<[u8; 20]>(),
+        position in 2_usize..42,
+    ) {
+        let checksummed = Address::from(address).to_checksum(None);
+        let mut characters = checksummed.chars().collect::<Vec<_>>();
+
+        prop_assume!(characters[position].is_ascii_alphabetic());
+        characters[position] = if characters[position].is_ascii_uppercase() {
+            characters[position].to_ascii_lowercase()
+        } else {
+            characters[position].to_ascii_uppercase()
+        };
+
+        let corrupted = characters.into_iter().collect::<String>();
+        let is_mixed_case = corrupted[2..].chars().any(|digit| digit.is_ascii_uppercase())
+            && corrupted[2..].chars().any(|digit| digit.is_ascii_lowercase());
+        prop_assume!(is_mixed_case);
+
+        let parsed = <EthereumAddress as ScalarType>::parse(async_graphql::Value::String(corrupted));
+
+        prop_assert!(parsed.is_err());
+    }
+
+    /// Tests if an [`EthereumSignature`] is parsed back from its GraphQL value.
+    #[test]
+    fn signature_round_trips_through_graphql(
+        signing_key in signing_key(),
+        application_id in application_id(),
+        campaign in campaign_id(),
+        destination in account(),
+    ) {
+        let signature = EthereumSignature::from(
+            sign_claim(&signing_key, application_id, campaign, destination, None),
+        );
+
+        let parsed = <EthereumSignature as ScalarType>::parse(ScalarType::to_value(&signature));
+
+        prop_assert_eq!(parsed.ok(), Some(signature));
+    }
+}
+
+/// Generates a valid Ethereum [`SigningKey`].
+fn signing_key() -> impl Strategy<Value = SigningKey> {
+    any::<[u8; 32]>().prop_filter_map("Invalid secret key", |bytes| {
+        SigningKey::from_slice(&bytes).ok()
+    })
+}
+
+/// Generates an [`ApplicationId`] for the airdrop application.
+fn application_id() -> impl Strategy<Value = ApplicationId<ApplicationAbi>> {
+    (any::<String>(), any::<u32>())
+        .prop_map(|(name, index)| create_dummy_application_id(&name, index))
+}
+
+/// Generates a [`CampaignId`].
+fn campaign_id() -> impl Strategy<Value = CampaignId> {
+    any::<u32>().prop_map(CampaignId)
+}
+
+/// Generates an [`Amount`].
+fn amount() -> impl Strategy<Value = Amount> {
+    any::<u128>().prop_map(Amount::from_attos)
+}
+
+/// Generates a destination [`Account`], owned by a user or by an application.
+fn account() -> impl Strategy<Value = Account> {
+    let owner = prop_oneof![
+        any::<String>().prop_map(|seed| AccountOwner::User(Owner(CryptoHash::test_hash(seed)))),
+        (any::<String>(), any::<u32>()).prop_map(|(name, index)| {
+            AccountOwner::Application(create_dummy_application_id(&name, index))
+        }),
+    ];
+
+    (any::<String>(), owner).prop_map(|(chain_seed, owner)| Account {
+        chain_id: ChainId(CryptoHash::test_hash(chain_seed)),
+        owner,
+    })
+}
+
+/// Generates an [`AirDropId`].
+fn air_drop_id() -> impl Strategy<Value = AirDropId> {
+    (campaign_id(), any::<[u8; 20]>())
+        .prop_map(|(campaign, address)| AirDropId::new(campaign, Address::from(address)))
+}
+
+/// Generates a [`Network`], with any chain ID for the other networks.
+fn network() -> impl Strategy<Value = Network> {
+    prop_oneof![
+        Just(Network::Ethereum),
+        Just(Network::Polygon),
+        Just(Network::Arbitrum),
+        Just(Network::Base),
+        (1..u64::MAX).prop_map(|chain_id| Network::Other {
+            schema: "OTHER".to_owned(),
+            chain_id,
+        }),
+    ]
+}
+
+/// Generates an [`AllowlistProof`].
+fn allowlist_proof() -> impl Strategy<Value = AllowlistProof> {
+    (
+        amount(),
+        vec(
+            any::<[u8; 32]>().prop_map(|hash| EthereumHash(B256::from(hash))),
+            0..16,
+        ),
+    )
+        .prop_map(|(amount, proof)| AllowlistProof { amount, proof })
+}
+
+/// Generates an [`AirDropClaim`] signed by a random key.
+fn air_drop_claim() -> impl Strategy<Value = AirDropClaim> {
+    (
+        signing_key(),
+        application_id(),
+        campaign_id(),
+        account(),
+        any::<String>(),
+        option::of(amount()),
+        option::of(allowlist_proof()),
+    )
+        .prop_map(
+            |(
+                signing_key,
+                application_id,
+                campaign,
+                destination,
+                api_token,
+                relayer_fee,
+                allowlist_proof,
+            )| AirDropClaim {
+                campaign,
+                signature: sign_claim(
+                    &signing_key,
+                    application_id,
+                    campaign,
+                    destination,
+                    relayer_fee,
+                )
+                .into(),
+                destination,
+                api_token,
+                relayer_fee,
+                balance_proof: None,
+                allowlist_proof,
+            },
+        )
+}
@@ -6,8 +6,18 @@ import logo from './logo.svg';
 import './App.css';
 
 const CLAIM_AIRDROP = gql`
-    mutation AirDropClaim($destination: FungibleAccount!, $signature: String!, $apiToken: String!) {
-        airDropClaim(destination: $destination, signature: $signature, apiToken: $apiToken)
+    mutation AirDropClaim(
+        $campaign: CampaignId!,
+        $destination: FungibleAccount!,
+        $signature: Signature!,
+        $apiToken: String!,
+    ) {
+        airDropClaim(
+            campaign: $campaign,
+            destination: $destination,
+            signature: $signature,
+            apiToken: $apiToken,
+        )
     }
 `;
 
@@ -15,13 +25,14 @@ const ETHEREUM_MAINNET_CHAIN_ID = 1;
 
 type AppProps = {
   appId: string,
+  campaignId: number,
   chainId: string,
   owner: string,
   userAccount?: string,
   web3Provider?: EIP6963ProviderDetail,
 };
 
-function App({ appId, chainId, owner, userAccount, web3Provider }: AppProps) {
+function App({ appId, campaignId, chainId, owner, userAccount, web3Provider }: AppProps) {
   const [apiToken, setApiToken] = useState("")
   const [claim] = useMutation<AirDropClaimMutation>(CLAIM_AIRDROP, {
     onError: (error) => console.log(error),
@@ -66,7 +77,9 @@ function App({ appId, chainId, owner, userAccount, web3Provider }: AppProps) {
         ],
         AirDropClaim: [
           { name: "appId", type: "string" },
+          { name: "campaignId", type: "uint32" },
           { name: "claimer", type: "FungibleAccount" },
+          { name: "relayerFee", type: "uint256" },
         ],
         FungibleAccount: [
           { name: "chainId", type: "string" },
@@ -75,11 +88,14 @@ function App({ appId, chainId, owner, userAccount, web3Provider }: AppProps) {
       },
       message: {
         appId,
+        campaignId,
         claimer,
+        relayerFee: 0,
       },
     }).then((signature) => {
         claim({
           variables: {
+            campaign: campaignId,
             signature,
             destination: claimer,
             apiToken,
@@ -31,6 +31,7 @@ function GraphQLApp() {
     const [searchParams] = useSearchParams();
 
     let app = searchParams.get("app");
+    let campaign = searchParams.get("campaign");
     let owner = searchParams.get("owner");
     let host = searchParams.get("host");
     let port = searchParams.get("port");
@@ -41,6 +42,9 @@ function GraphQLApp() {
     if (app == null) {
         throw Error("The URL is missing an `app` query parameter with the application ID");
     }
+    if (campaign == null) {
+        throw Error("The URL is missing a `campaign` query parameter with the campaign ID");
+    }
     if (owner == null) {
         throw Error("The URL is missing an `owner` query parameter with the owner ID");
     }
@@ -62,7 +66,7 @@ function GraphQLApp() {
     return (
         <GraphQLProvider chainId={chainId} applicationId={app} host={host} port={port}>
             <AccountProvider>
-                <App appId={app} chainId={chainId} owner={owner} />
+                <App appId={app} campaignId={Number(campaign)} chainId={chainId} owner={owner} />
             </AccountProvider>
         </GraphQLProvider>
     );
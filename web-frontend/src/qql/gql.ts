@@ -13,7 +13,7 @@ import { TypedDocumentNode as DocumentNode } from '@graphql-typed-document-node/
  * Therefore it is highly recommended to use the babel or swc plugin for production.
  */
 const documents = {
-    "\n    mutation AirDropClaim($destination: FungibleAccount!, $signature: String!, $apiToken: String!) {\n        airDropClaim(destination: $destination, signature: $signature, apiToken: $apiToken)\n    }\n": types.AirDropClaimDocument,
+    "\n    mutation AirDropClaim(\n        $campaign: CampaignId!,\n        $destination: FungibleAccount!,\n        $signature: Signature!,\n        $apiToken: String!,\n    ) {\n        airDropClaim(\n            campaign: $campaign,\n            destination: $destination,\n            signature: $signature,\n            apiToken: $apiToken,\n        )\n    }\n": types.AirDropClaimDocument,
 };
 
 /**
@@ -33,7 +33,7 @@ export function graphql(source: string): unknown;
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
-export function graphql(source: "\n    mutation AirDropClaim($destination: FungibleAccount!, $signature: String!, $apiToken: String!) {\n        airDropClaim(destination: $destination, signature: $signature, apiToken: $apiToken)\n    }\n"): (typeof documents)["\n    mutation AirDropClaim($destination: FungibleAccount!, $signature: String!, $apiToken: String!) {\n        airDropClaim(destination: $destination, signature: $signature, apiToken: $apiToken)\n    }\n"];
+export function graphql(source: "\n    mutation AirDropClaim(\n        $campaign: CampaignId!,\n        $destination: FungibleAccount!,\n        $signature: Signature!,\n        $apiToken: String!,\n    ) {\n        airDropClaim(\n            campaign: $campaign,\n            destination: $destination,\n            signature: $signature,\n            apiToken: $apiToken,\n        )\n    }\n"): (typeof documents)["\n    mutation AirDropClaim(\n        $campaign: CampaignId!,\n        $destination: FungibleAccount!,\n        $signature: Signature!,\n        $apiToken: String!,\n    ) {\n        airDropClaim(\n            campaign: $campaign,\n            destination: $destination,\n            signature: $signature,\n            apiToken: $apiToken,\n        )\n    }\n"];
 
 export function graphql(source: string) {
   return (documents as any)[source] ?? {};
@@ -16,15 +16,370 @@ export type Scalars = {
   Float: { input: number; output: number; }
   /** An owner of an account. */
   AccountOwner: { input: any; output: any; }
+  /** A non-negative amount of tokens. */
+  Amount: { input: any; output: any; }
+  /** A unique identifier for a user application */
+  ApplicationId: { input: any; output: any; }
+  CampaignId: { input: any; output: any; }
   /** The unique identifier (UID) of a chain. This is currently computed as the hash value of a ChainDescription. */
   ChainId: { input: any; output: any; }
+  EthereumAddress: { input: any; output: any; }
+  EthereumHash: { input: any; output: any; }
+  /** In GraphQL, a [`Network`] is a string in the format parsed by its [`FromStr`] implementation. */
+  Network: { input: any; output: any; }
+  /** The owner of a chain. This is currently the hash of the owner's public key used to verify signatures. */
+  Owner: { input: any; output: any; }
+  Signature: { input: any; output: any; }
+  /** A duration in microseconds */
+  TimeDelta: { input: any; output: any; }
+  /** A timestamp, in microseconds since the Unix epoch */
+  Timestamp: { input: any; output: any; }
+  U256: { input: any; output: any; }
 };
 
-/** Empty additional fields */
-export type EmptyFields = {
-  __typename?: 'EmptyFields';
+/** An account. */
+export type Account = {
+  __typename?: 'Account';
+  /** Chain ID of the account */
+  chainId: Scalars['ChainId']['output'];
+  /** Owner of the account */
+  owner: Scalars['AccountOwner']['output'];
+};
+
+export type ActivityRule = {
+  __typename?: 'ActivityRule';
+  /** The address must have sent at least one transaction to this contract. */
+  contractInteraction?: Maybe<Scalars['EthereumAddress']['output']>;
+  /** The address's first transaction must be before this block. */
+  firstSeenBefore?: Maybe<Scalars['Int']['output']>;
+  /** The address must have sent at least this number of transactions. */
+  transactionCount?: Maybe<Scalars['Int']['output']>;
+};
+
+/** A rule on an address's on-chain activity up to the snapshot block. */
+export type ActivityRuleInput =
+  /** The address must have sent at least one transaction to this contract. */
+  { contractInteraction: Scalars['EthereumAddress']['input']; firstSeenBefore?: never; transactionCount?: never; }
+  |  /** The address's first transaction must be before this block. */
+  { contractInteraction?: never; firstSeenBefore: Scalars['Int']['input']; transactionCount?: never; }
+  |  /** The address must have sent at least this number of transactions. */
+  { contractInteraction?: never; firstSeenBefore?: never; transactionCount: Scalars['Int']['input']; };
+
+/** An airdrop claim. */
+export type AirDropClaim = {
+  /**
+   * A proof that the claimer is in the campaign's allowlist, required by campaigns with a
+   * [`Criterion::Allowlisted`] rule.
+   */
+  allowlistProof?: InputMaybe<AllowlistProof>;
+  /**
+   * The Space-and-Time API token used to check the claimer's eligibility through the
+   * Gateway.
+   */
+  apiToken?: Scalars['String']['input'];
+  /** An optional Proof-of-SQL result for the claimer's balance, replacing the Gateway query. */
+  balanceProof?: InputMaybe<BalanceProof>;
+  /** The campaign to claim the airdrop from. */
+  campaign: Scalars['CampaignId']['input'];
+  /** The account that receives the airdropped tokens. */
+  destination: FungibleAccount;
+  /** An optional fee paid from the airdropped tokens to the relayer that submits the claim. */
+  relayerFee?: InputMaybe<Scalars['Amount']['input']>;
+  /** The claimer's EIP-712 signature of the claim. */
+  signature: Scalars['Signature']['input'];
+};
+
+export type AirDropId = {
+  __typename?: 'AirDropId';
+  /**
+   * The Ethereum address that claims the airdrop, with the [EIP-1191] checksum of the
+   * [`Network`] that the service adds to the GraphQL context.
+   *
+   * [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+   */
+  address: Scalars['String']['output'];
+  /** The campaign the airdrop belongs to. */
+  campaign: Scalars['CampaignId']['output'];
+};
+
+/** The information necessary to identify an airdrop. */
+export type AirDropIdInput = {
+  /**
+   * The Ethereum address that claims the airdrop, with the EIP-55 checksum or the EIP-1191
+   * checksum of the configured network, as displayed by the `AirDropId` object.
+   */
+  address: Scalars['String']['input'];
+  /** The campaign the airdrop belongs to. */
+  campaign: Scalars['CampaignId']['input'];
+};
+
+/** A proof that an address is in a campaign's allowlist with an `amount`. */
+export type AllowlistProof = {
+  /** The amount allowlisted for the address. */
+  amount: Scalars['Amount']['input'];
+  /** The sibling hashes from the address's leaf up to the root. */
+  proof: Array<Scalars['EthereumHash']['input']>;
+};
+
+export type AmountPolicy = {
+  __typename?: 'AmountPolicy';
+  /** Each claimer receives the amount set for their address in the campaign's allowlist. */
+  allowlisted?: Maybe<Scalars['Boolean']['output']>;
+  /** Every claimer receives this amount. */
+  fixed?: Maybe<Scalars['Amount']['output']>;
+};
+
+/** How much is airdropped for each approved claim. */
+export type AmountPolicyInput =
+  /**
+   * Each claimer receives the amount set for their address in the campaign's allowlist. The
+   * value is ignored.
+   */
+  { allowlisted: Scalars['Boolean']['input']; fixed?: never; }
+  |  /** Every claimer receives this amount. */
+  { allowlisted?: never; fixed: Scalars['Amount']['input']; };
+
+/** A minimum native token balance, held according to a holding rule. */
+export type BalanceCriterion = {
+  __typename?: 'BalanceCriterion';
+  holding: HoldingRule;
+  minimum: Scalars['U256']['output'];
+};
+
+/**
+ * A minimum native token balance, held according to a holding rule, which is the balance at the
+ * snapshot block if it's omitted.
+ */
+export type BalanceCriterionInput = {
+  holding?: InputMaybe<HoldingRuleInput>;
+  minimum: Scalars['U256']['input'];
+};
+
+/** A Proof-of-SQL result for the balance query of a claimer's address at a snapshot block. */
+export type BalanceProof = {
+  /** The block used as the balance snapshot in the proven query. */
+  snapshotBlock: Scalars['Int']['input'];
+  /** The BCS serialized [`VerifiableQueryResult`] returned by the prover. */
+  verifiableResult: Array<Scalars['Int']['input']>;
+};
+
+/** The tokens funded into and paid by a campaign. */
+export type Budget = {
+  __typename?: 'Budget';
+  funded: Scalars['Amount']['output'];
+  paid: Scalars['Amount']['output'];
+  /** The amount of tokens that are still available to be paid. */
+  remaining: Scalars['Amount']['output'];
+};
+
+/** The configuration of an airdrop campaign. */
+export type Campaign = {
+  __typename?: 'Campaign';
+  amount: AmountPolicy;
+  eligibility: Eligibility;
+  vesting?: Maybe<VestingSchedule>;
+  window: ClaimWindow;
+};
+
+/** The configuration of a new airdrop campaign. */
+export type CampaignInput = {
+  amount: AmountPolicyInput;
+  eligibility: EligibilityInput;
+  vesting?: InputMaybe<VestingScheduleInput>;
+  window: ClaimWindowInput;
+};
+
+/** A record of an approved claim, with the evidence of the claimer's eligibility. */
+export type ClaimRecord = {
+  __typename?: 'ClaimRecord';
+  amount: Scalars['Amount']['output'];
+  approvedAt: Scalars['Timestamp']['output'];
+  destination: Account;
+  evidence: EligibilityEvidence;
+  relayerFee?: Maybe<Scalars['Amount']['output']>;
+};
+
+/** The period in which a campaign accepts claims. */
+export type ClaimWindow = {
+  __typename?: 'ClaimWindow';
+  end: Scalars['Timestamp']['output'];
+  start: Scalars['Timestamp']['output'];
+};
+
+/** The period in which a campaign accepts claims. */
+export type ClaimWindowInput = {
+  end: Scalars['Timestamp']['input'];
+  start: Scalars['Timestamp']['input'];
 };
 
+/** The result of evaluating one clause of an [`EligibilityRule`]. */
+export type ClauseResult = {
+  __typename?: 'ClauseResult';
+  /** The clause, as an expression in the rule language. */
+  clause: Scalars['String']['output'];
+  /** Whether the clause is satisfied. */
+  satisfied: Scalars['Boolean']['output'];
+  /** The value read from Space-and-Time for a single criterion, if there was one. */
+  value?: Maybe<Scalars['String']['output']>;
+};
+
+/**
+ * A minimum sum of the native token balances on several networks, each at its own snapshot
+ * block.
+ */
+export type CombinedBalanceCriterion = {
+  __typename?: 'CombinedBalanceCriterion';
+  minimum: Scalars['U256']['output'];
+  snapshots: Array<NetworkSnapshot>;
+};
+
+/**
+ * A minimum sum of the native token balances on several networks, each at its own snapshot
+ * block.
+ */
+export type CombinedBalanceCriterionInput = {
+  minimum: Scalars['U256']['input'];
+  snapshots: Array<NetworkSnapshotInput>;
+};
+
+export type Criterion = {
+  __typename?: 'Criterion';
+  /** An on-chain activity rule must be met. */
+  activity?: Maybe<ActivityRule>;
+  /** The address must be in the Merkle allowlist with this root. */
+  allowlisted?: Maybe<Scalars['EthereumHash']['output']>;
+  /** The native token balance, held according to a holding rule, must be at least a minimum. */
+  balance?: Maybe<BalanceCriterion>;
+  /** The sum of the native token balances on several networks must be at least a minimum. */
+  combinedBalance?: Maybe<CombinedBalanceCriterion>;
+  /** The address must be in the application's denylist. */
+  denylisted?: Maybe<Scalars['Boolean']['output']>;
+  /** This application must report the claimer as a member. */
+  lineraApplicationMember?: Maybe<Scalars['ApplicationId']['output']>;
+  /** The claimer must be one of these Linera identities. */
+  lineraMember?: Maybe<Array<LineraIdentity>>;
+  /** The claimer must be a Linera user with at least a minimum balance of a fungible token. */
+  lineraTokenBalance?: Maybe<LineraTokenBalanceCriterion>;
+  /** The balance of an ERC-20 token must be at least a minimum. */
+  tokenBalance?: Maybe<TokenBalanceCriterion>;
+};
+
+/** A single criterion on an address's balances or activity, or on a Linera identity. */
+export type CriterionInput =
+  /** An on-chain activity rule must be met. */
+  { activity: ActivityRuleInput; allowlisted?: never; balance?: never; combinedBalance?: never; denylisted?: never; lineraApplicationMember?: never; lineraMember?: never; lineraTokenBalance?: never; tokenBalance?: never; }
+  |  /** The address must be in the Merkle allowlist with this root. */
+  { activity?: never; allowlisted: Scalars['EthereumHash']['input']; balance?: never; combinedBalance?: never; denylisted?: never; lineraApplicationMember?: never; lineraMember?: never; lineraTokenBalance?: never; tokenBalance?: never; }
+  |  /** The native token balance, held according to a holding rule, must be at least a minimum. */
+  { activity?: never; allowlisted?: never; balance: BalanceCriterionInput; combinedBalance?: never; denylisted?: never; lineraApplicationMember?: never; lineraMember?: never; lineraTokenBalance?: never; tokenBalance?: never; }
+  |  /** The sum of the native token balances on several networks must be at least a minimum. */
+  { activity?: never; allowlisted?: never; balance?: never; combinedBalance: CombinedBalanceCriterionInput; denylisted?: never; lineraApplicationMember?: never; lineraMember?: never; lineraTokenBalance?: never; tokenBalance?: never; }
+  |  /** The address must be in the application's denylist. The value is ignored. */
+  { activity?: never; allowlisted?: never; balance?: never; combinedBalance?: never; denylisted: Scalars['Boolean']['input']; lineraApplicationMember?: never; lineraMember?: never; lineraTokenBalance?: never; tokenBalance?: never; }
+  |  /** This application must report the claimer as a member. */
+  { activity?: never; allowlisted?: never; balance?: never; combinedBalance?: never; denylisted?: never; lineraApplicationMember: Scalars['ApplicationId']['input']; lineraMember?: never; lineraTokenBalance?: never; tokenBalance?: never; }
+  |  /** The claimer must be one of these Linera identities. */
+  { activity?: never; allowlisted?: never; balance?: never; combinedBalance?: never; denylisted?: never; lineraApplicationMember?: never; lineraMember: Array<LineraIdentityInput>; lineraTokenBalance?: never; tokenBalance?: never; }
+  |  /** The claimer must be a Linera user with at least a minimum balance of a fungible token. */
+  { activity?: never; allowlisted?: never; balance?: never; combinedBalance?: never; denylisted?: never; lineraApplicationMember?: never; lineraMember?: never; lineraTokenBalance: LineraTokenBalanceCriterionInput; tokenBalance?: never; }
+  |  /** The balance of an ERC-20 token must be at least a minimum. */
+  { activity?: never; allowlisted?: never; balance?: never; combinedBalance?: never; denylisted?: never; lineraApplicationMember?: never; lineraMember?: never; lineraTokenBalance?: never; tokenBalance: TokenBalanceCriterionInput; };
+
+/**
+ * An entry in the denylist, preventing tokens from being airdropped to or through it.
+ *
+ * In GraphQL inputs, exactly one of the fields must be set.
+ */
+export type DenylistEntryInput =
+  /** A chain that can't receive airdropped tokens or relayer fees. */
+  { chain: Scalars['ChainId']['input']; externalAddress?: never; owner?: never; }
+  |  /** An Ethereum address that can't claim airdrops. */
+  { chain?: never; externalAddress: Scalars['EthereumAddress']['input']; owner?: never; }
+  |  /** An account owner that can't receive airdropped tokens or relayer fees. */
+  { chain?: never; externalAddress?: never; owner: Scalars['AccountOwner']['input']; };
+
+/** A record of tokens deposited into a campaign's budget. */
+export type Deposit = {
+  __typename?: 'Deposit';
+  amount: Scalars['Amount']['output'];
+  campaign: Scalars['CampaignId']['output'];
+  funder: Scalars['AccountOwner']['output'];
+  timestamp: Scalars['Timestamp']['output'];
+};
+
+/** The criteria an Ethereum address must meet to be eligible for a campaign's airdrop. */
+export type Eligibility = {
+  __typename?: 'Eligibility';
+  /** The rule that the address must satisfy. */
+  rule: EligibilityRule;
+  /**
+   * The block up to which the address's balances and activity are read, on the network
+   * configured in the [`Parameters`].
+   */
+  snapshotBlock: Scalars['Int']['output'];
+};
+
+/**
+ * The outcome of checking an address's eligibility for a campaign, together with the evidence
+ * it's based on.
+ */
+export type EligibilityEvidence = {
+  __typename?: 'EligibilityEvidence';
+  /** The amount airdropped to the address, which is zero if it isn't eligible. */
+  amount: Scalars['Amount']['output'];
+  /** The native token balance observed for the address, if the rule reads one. */
+  balance?: Maybe<Scalars['U256']['output']>;
+  /** The snapshot block the address's balances and activity were read at. */
+  block: Scalars['Int']['output'];
+  /** Whether the address is eligible. */
+  eligible: Scalars['Boolean']['output'];
+  /** The part of the campaign's rule that the address satisfied, if it's eligible. */
+  matchedRule?: Maybe<Scalars['String']['output']>;
+};
+
+/** The criteria an Ethereum address must meet to be eligible for a campaign's airdrop. */
+export type EligibilityInput = {
+  /** The rule that the address must satisfy. */
+  rule: EligibilityRuleInput;
+  /**
+   * The block up to which the address's balances and activity are read, on the network
+   * configured in the application's parameters.
+   */
+  snapshotBlock: Scalars['Int']['input'];
+};
+
+/** The result of evaluating a campaign's [`EligibilityRule`] for an address. */
+export type EligibilityReport = {
+  __typename?: 'EligibilityReport';
+  /** The results of the rule's clauses, in evaluation order, ending with the whole rule. */
+  clauses: Array<ClauseResult>;
+  /** Whether the address is eligible. */
+  eligible: Scalars['Boolean']['output'];
+};
+
+export type EligibilityRule = {
+  __typename?: 'EligibilityRule';
+  /** Satisfied if all of the rules are satisfied, or if there are no rules. */
+  all?: Maybe<Array<EligibilityRule>>;
+  /** Satisfied if any of the rules is satisfied, but not if there are no rules. */
+  any?: Maybe<Array<EligibilityRule>>;
+  /** A single criterion. */
+  criterion?: Maybe<Criterion>;
+  /** Satisfied if the rule isn't satisfied. */
+  not?: Maybe<EligibilityRule>;
+};
+
+/** A boolean expression combining eligibility criteria. */
+export type EligibilityRuleInput =
+  /** Satisfied if all of the rules are satisfied, or if there are no rules. */
+  { all: Array<EligibilityRuleInput>; any?: never; criterion?: never; not?: never; }
+  |  /** Satisfied if any of the rules is satisfied, but not if there are no rules. */
+  { all?: never; any: Array<EligibilityRuleInput>; criterion?: never; not?: never; }
+  |  /** A single criterion. */
+  { all?: never; any?: never; criterion: CriterionInput; not?: never; }
+  |  /** Satisfied if the rule isn't satisfied. */
+  { all?: never; any?: never; criterion?: never; not: EligibilityRuleInput; };
+
 /** An account. */
 export type FungibleAccount = {
   /** Chain ID of the account */
@@ -33,22 +388,318 @@ export type FungibleAccount = {
   owner: Scalars['AccountOwner']['input'];
 };
 
+export type HoldingRule = {
+  __typename?: 'HoldingRule';
+  /** The balance at the snapshot block must meet the minimum. */
+  atSnapshot?: Maybe<Scalars['Boolean']['output']>;
+  /**
+   * The balance must meet the minimum at every block from this start block to the snapshot
+   * block.
+   */
+  continuous?: Maybe<Scalars['Int']['output']>;
+  /**
+   * The time-weighted average balance from this start block to the snapshot block must meet
+   * the minimum.
+   */
+  timeWeightedAverage?: Maybe<Scalars['Int']['output']>;
+};
+
+/** How a balance must be held for an address to be eligible. */
+export type HoldingRuleInput =
+  /** The balance at the snapshot block must meet the minimum. The value is ignored. */
+  { atSnapshot: Scalars['Boolean']['input']; continuous?: never; timeWeightedAverage?: never; }
+  |  /**
+   * The balance must meet the minimum at every block from this start block to the snapshot
+   * block.
+   */
+  { atSnapshot?: never; continuous: Scalars['Int']['input']; timeWeightedAverage?: never; }
+  |  /**
+   * The time-weighted average balance from this start block to the snapshot block must meet
+   * the minimum.
+   */
+  { atSnapshot?: never; continuous?: never; timeWeightedAverage: Scalars['Int']['input']; };
+
+/** The information necessary to identify an airdrop claimed by a [`LineraIdentity`]. */
+export type LineraAirDropIdInput = {
+  /** The campaign the airdrop belongs to. */
+  campaign: Scalars['CampaignId']['input'];
+  /** The Linera identity that claims the airdrop. */
+  claimer: LineraIdentityInput;
+};
+
+/** In GraphQL outputs, only the field of the identity's variant is set. */
+export type LineraIdentity = {
+  __typename?: 'LineraIdentity';
+  /** A chain, whose owners can claim by submitting the claim from it. */
+  chain?: Maybe<Scalars['ChainId']['output']>;
+  /** A user, authenticated as the signer of the claim. */
+  owner?: Maybe<Scalars['Owner']['output']>;
+};
+
+/**
+ * A Linera identity that can claim airdrops from campaigns with a rule on Linera identities.
+ *
+ * In GraphQL inputs, exactly one of the fields must be set.
+ */
+export type LineraIdentityInput =
+  /** A chain, whose owners can claim by submitting the claim from it. */
+  { chain: Scalars['ChainId']['input']; owner?: never; }
+  |  /** A user, authenticated as the signer of the claim. */
+  { chain?: never; owner: Scalars['Owner']['input']; };
+
+/** A minimum balance of a Linera fungible token on the application's creator chain. */
+export type LineraTokenBalanceCriterion = {
+  __typename?: 'LineraTokenBalanceCriterion';
+  minimum: Scalars['Amount']['output'];
+  token: Scalars['ApplicationId']['output'];
+};
+
+/** A minimum balance of a Linera fungible token on the application's creator chain. */
+export type LineraTokenBalanceCriterionInput = {
+  minimum: Scalars['Amount']['input'];
+  token: Scalars['ApplicationId']['input'];
+};
+
 export type Mutation = {
   __typename?: 'Mutation';
-  /** Claims an airdrop. */
+  /**
+   * Claims an airdrop from a campaign.
+   *
+   * The claim may be submitted by a relayer, in which case the claimer may have signed an
+   * optional `relayer_fee` to be paid to the relayer from the airdropped tokens.
+   *
+   * The claimer's eligibility is checked through the Space-and-Time Gateway with the
+   * `api_token`, unless a Proof-of-SQL `balance_proof` is provided. Campaigns with an
+   * allowlist require an `allowlist_proof` instead.
+   */
   airDropClaim: Array<Scalars['Int']['output']>;
+  /**
+   * Claims multiple airdrops in a single operation.
+   *
+   * Each claim is handled independently, so invalid claims are skipped without preventing
+   * the others from being paid.
+   */
+  airDropClaimBatch: Array<Scalars['Int']['output']>;
+  /** Creates a new airdrop campaign. */
+  createCampaign: Array<Scalars['Int']['output']>;
+  /** Funds a campaign with tokens from the signer's account. */
+  fund: Array<Scalars['Int']['output']>;
+  /**
+   * Claims an airdrop from a campaign with Linera members, as the signer of the operation
+   * or as the chain it's submitted from.
+   */
+  lineraClaim: Array<Scalars['Int']['output']>;
+  /** Releases the vested tokens of an airdrop. */
+  release: Array<Scalars['Int']['output']>;
+  /** Adds and removes entries from the denylist. */
+  updateDenylist: Array<Scalars['Int']['output']>;
 };
 
 
 export type MutationAirDropClaimArgs = {
-  apiToken: Scalars['String']['input'];
+  allowlistProof?: InputMaybe<AllowlistProof>;
+  apiToken?: Scalars['String']['input'];
+  balanceProof?: InputMaybe<BalanceProof>;
+  campaign: Scalars['CampaignId']['input'];
+  destination: FungibleAccount;
+  relayerFee?: InputMaybe<Scalars['Amount']['input']>;
+  signature: Scalars['Signature']['input'];
+};
+
+
+export type MutationAirDropClaimBatchArgs = {
+  claims: Array<AirDropClaim>;
+};
+
+
+export type MutationCreateCampaignArgs = {
+  campaign: CampaignInput;
+};
+
+
+export type MutationFundArgs = {
+  amount: Scalars['Amount']['input'];
+  campaign: Scalars['CampaignId']['input'];
+};
+
+
+export type MutationLineraClaimArgs = {
+  campaign: Scalars['CampaignId']['input'];
   destination: FungibleAccount;
-  signature: Scalars['String']['input'];
+};
+
+
+export type MutationReleaseArgs = {
+  id: AirDropIdInput;
+};
+
+
+export type MutationUpdateDenylistArgs = {
+  add?: Array<DenylistEntryInput>;
+  remove?: Array<DenylistEntryInput>;
+};
+
+/** A snapshot block on a specific [`Network`]. */
+export type NetworkSnapshot = {
+  __typename?: 'NetworkSnapshot';
+  block: Scalars['Int']['output'];
+  network: Scalars['Network']['output'];
+};
+
+/** A snapshot block on a specific [`Network`]. */
+export type NetworkSnapshotInput = {
+  block: Scalars['Int']['input'];
+  network: Scalars['Network']['input'];
+};
+
+export type Query = {
+  __typename?: 'Query';
+  /** Returns the amounts funded, paid and remaining in a campaign's budget. */
+  budget: Budget;
+  /** Returns the configuration of a campaign, if it exists. */
+  campaign?: Maybe<Campaign>;
+  /**
+   * Checks if an address is eligible to claim an airdrop from a campaign, returning the
+   * evidence the result is based on.
+   */
+  checkEligibility: EligibilityEvidence;
+  /** Returns the record of an approved claim, with the evidence of the claimer's eligibility. */
+  claimRecord?: Maybe<ClaimRecord>;
+  /** Lists all deposits made into the campaigns' budgets, in the order they were recorded. */
+  deposits: Array<Deposit>;
+  /**
+   * Evaluates a campaign's eligibility rule for an address, reporting the result of each of
+   * the rule's clauses.
+   */
+  eligibilityReport: EligibilityReport;
+  /** Returns the total amount deposited by a `funder` across all campaigns. */
+  fundedBy: Scalars['Amount']['output'];
+  /** Checks if an `entry` is in the denylist. */
+  isDenied: Scalars['Boolean']['output'];
+  /**
+   * Checks if a Linera `identity` is one of the members of a campaign with a
+   * [`Criterion::LineraMember`] rule.
+   */
+  isLineraMember: Scalars['Boolean']['output'];
+  /** Returns the record of an approved claim by a Linera identity. */
+  lineraClaimRecord?: Maybe<ClaimRecord>;
+  /** Lists all claims that were rejected by the creator chain, in the order they were recorded. */
+  rejectedClaims: Array<RejectedClaim>;
+  /** Returns the vesting position of an airdrop, if it has one. */
+  vestingPosition?: Maybe<VestingPosition>;
+};
+
+
+export type QueryBudgetArgs = {
+  campaign: Scalars['CampaignId']['input'];
+};
+
+
+export type QueryCampaignArgs = {
+  id: Scalars['CampaignId']['input'];
+};
+
+
+export type QueryCheckEligibilityArgs = {
+  address: Scalars['EthereumAddress']['input'];
+  apiToken: Scalars['String']['input'];
+  campaign: Scalars['CampaignId']['input'];
+};
+
+
+export type QueryClaimRecordArgs = {
+  id: AirDropIdInput;
+};
+
+
+export type QueryEligibilityReportArgs = {
+  address: Scalars['EthereumAddress']['input'];
+  apiToken: Scalars['String']['input'];
+  campaign: Scalars['CampaignId']['input'];
+};
+
+
+export type QueryFundedByArgs = {
+  funder: Scalars['AccountOwner']['input'];
+};
+
+
+export type QueryIsDeniedArgs = {
+  entry: DenylistEntryInput;
+};
+
+
+export type QueryIsLineraMemberArgs = {
+  campaign: Scalars['CampaignId']['input'];
+  identity: LineraIdentityInput;
+};
+
+
+export type QueryLineraClaimRecordArgs = {
+  id: LineraAirDropIdInput;
+};
+
+
+export type QueryVestingPositionArgs = {
+  id: AirDropIdInput;
+};
+
+/** A record of a claim that was rejected by the creator chain. */
+export type RejectedClaim = {
+  __typename?: 'RejectedClaim';
+  destination: Account;
+  id: AirDropId;
+  reason: Scalars['String']['output'];
+  timestamp: Scalars['Timestamp']['output'];
+};
+
+/** A minimum balance of an ERC-20 token contract. */
+export type TokenBalanceCriterion = {
+  __typename?: 'TokenBalanceCriterion';
+  minimum: Scalars['U256']['output'];
+  token: Scalars['EthereumAddress']['output'];
+};
+
+/** A minimum balance of an ERC-20 token contract. */
+export type TokenBalanceCriterionInput = {
+  minimum: Scalars['U256']['input'];
+  token: Scalars['EthereumAddress']['input'];
+};
+
+/** The tokens of an approved airdrop that are released according to a vesting schedule. */
+export type VestingPosition = {
+  __typename?: 'VestingPosition';
+  destination: Account;
+  released: Scalars['Amount']['output'];
+  start: Scalars['Timestamp']['output'];
+  total: Scalars['Amount']['output'];
+};
+
+/** A linear vesting schedule for the airdropped tokens. */
+export type VestingSchedule = {
+  __typename?: 'VestingSchedule';
+  /** The time after an airdrop is approved before any of its tokens vest. */
+  cliff: Scalars['TimeDelta']['output'];
+  /** The time after an airdrop is approved for all of its tokens to vest. */
+  duration: Scalars['TimeDelta']['output'];
+  /** The granularity in which tokens vest. */
+  releaseInterval: Scalars['TimeDelta']['output'];
+};
+
+/** A linear vesting schedule for the airdropped tokens. */
+export type VestingScheduleInput = {
+  /** The time after an airdrop is approved before any of its tokens vest. */
+  cliff: Scalars['TimeDelta']['input'];
+  /** The time after an airdrop is approved for all of its tokens to vest. */
+  duration: Scalars['TimeDelta']['input'];
+  /** The granularity in which tokens vest. */
+  releaseInterval: Scalars['TimeDelta']['input'];
 };
 
 export type AirDropClaimMutationVariables = Exact<{
+  campaign: Scalars['CampaignId']['input'];
   destination: FungibleAccount;
-  signature: Scalars['String']['input'];
+  signature: Scalars['Signature']['input'];
   apiToken: Scalars['String']['input'];
 }>;
 
@@ -56,4 +707,4 @@ export type AirDropClaimMutationVariables = Exact<{
 export type AirDropClaimMutation = { __typename?: 'Mutation', airDropClaim: Array<number> };
 
 
-export const AirDropClaimDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"AirDropClaim"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"destination"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"FungibleAccount"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"signature"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"apiToken"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"airDropClaim"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"destination"},"value":{"kind":"Variable","name":{"kind":"Name","value":"destination"}}},{"kind":"Argument","name":{"kind":"Name","value":"signature"},"value":{"kind":"Variable","name":{"kind":"Name","value":"signature"}}},{"kind":"Argument","name":{"kind":"Name","value":"apiToken"},"value":{"kind":"Variable","name":{"kind":"Name","value":"apiToken"}}}]}]}}]} as unknown as DocumentNode<AirDropClaimMutation, AirDropClaimMutationVariables>;
\ No newline at end of file
+export const AirDropClaimDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"AirDropClaim"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"campaign"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"CampaignId"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"destination"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"FungibleAccount"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"signature"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Signature"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"apiToken"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"airDropClaim"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"campaign"},"value":{"kind":"Variable","name":{"kind":"Name","value":"campaign"}}},{"kind":"Argument","name":{"kind":"Name","value":"destination"},"value":{"kind":"Variable","name":{"kind":"Name","value":"destination"}}},{"kind":"Argument","name":{"kind":"Name","value":"signature"},"value":{"kind":"Variable","name":{"kind":"Name","value":"signature"}}},{"kind":"Argument","name":{"kind":"Name","value":"apiToken"},"value":{"kind":"Variable","name":{"kind":"Name","value":"apiToken"}}}]}]}}]} as unknown as DocumentNode<AirDropClaimMutation, AirDropClaimMutationVariables>;
\ No newline at end of file
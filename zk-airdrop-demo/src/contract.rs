@@ -1,12 +1,22 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
+#[cfg(test)]
+mod contract_unit_tests;
 mod state;
 
+use airdrop_demo::{balance_proof, network::Network, AirDropClaim, AirDropId};
+use alloy_primitives::U256;
 use linera_sdk::{
-    base::WithContractAbi,
-    views::{RootView, View, ViewStorageContext},
+    abis::fungible::{self, Account},
+    base::{AccountOwner, Amount, WithContractAbi},
+    views::{RootView, View},
     Contract, ContractRuntime,
 };
+use serde::{Deserialize, Serialize};
+use zk_airdrop_demo::{Operation, Parameters, AIRDROP_CAMPAIGN};
 
 use self::state::Application;
 
@@ -22,24 +32,169 @@ impl WithContractAbi for ApplicationContract {
 }
 
 impl Contract for ApplicationContract {
-    type Message = ();
-    type Parameters = ();
+    type Message = Message;
+    type Parameters = Parameters;
     type InstantiationArgument = ();
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
-        let state = Application::load(ViewStorageContext::from(runtime.key_value_store()))
+        let state = Application::load(runtime.root_view_storage_context())
             .await
             .expect("Failed to load state");
         ApplicationContract { state, runtime }
     }
 
-    async fn instantiate(&mut self, _argument: Self::InstantiationArgument) {}
+    /// Instantiates the application.
+    ///
+    /// Fails if the [`Parameters`] specified to create the application are invalid, or if the
+    /// token application doesn't exist or isn't one of the application's required applications.
+    async fn instantiate(&mut self, _argument: Self::InstantiationArgument) {
+        let parameters = self.runtime.application_parameters();
+
+        assert!(
+            balance_proof::deserialize_commitments(&parameters.proof_of_sql_commitments).is_ok(),
+            "Proof-of-SQL commitments could not be deserialized"
+        );
+        assert!(
+            parameters.minimum_balance > U256::ZERO,
+            "Minimum balance must not be zero"
+        );
+        assert!(
+            parameters.claim_amount > Amount::ZERO,
+            "Claim amount must not be zero"
+        );
 
-    async fn execute_operation(&mut self, _operation: Self::Operation) -> Self::Response {}
+        let owner = AccountOwner::Application(self.runtime.application_id().forget_abi());
+        let response = self.runtime.call_application(
+            false,
+            parameters.token_id,
+            &fungible::Operation::Balance { owner },
+        );
+
+        assert!(
+            matches!(response, fungible::FungibleResponse::Balance(_)),
+            "Token application is not a fungible token"
+        );
+    }
 
-    async fn execute_message(&mut self, _message: Self::Message) {}
+    /// Executes an [`Operation`], verifying claims on the chain that submits them.
+    async fn execute_operation(&mut self, operation: Self::Operation) -> Self::Response {
+        match operation {
+            Operation::Claim(claim) => self.claim(claim).await,
+        }
+    }
+
+    /// Pays the claims approved by the chains that submitted them.
+    async fn execute_message(&mut self, message: Self::Message) {
+        self.handle_message(message).await;
+    }
 
     async fn store(mut self) {
         self.state.save().await.expect("Failed to save state");
     }
 }
+
+impl ApplicationContract {
+    /// Verifies the signature and the balance proof of an [`AirDropClaim`], and sends the
+    /// approved claim to the application's creator chain to be paid.
+    ///
+    /// The proof is verified on the chain that submits the claim, so that the verification of
+    /// many claims can run in parallel.
+    async fn claim(&mut self, claim: AirDropClaim) {
+        let parameters = self.runtime.application_parameters();
+
+        assert_eq!(
+            claim.campaign, AIRDROP_CAMPAIGN,
+            "Claims must be signed for the airdrop's campaign"
+        );
+        assert!(
+            claim.relayer_fee.is_none(),
+            "Relayer fees are not supported by the airdrop"
+        );
+
+        let application_id = self.runtime.application_id().forget_abi().with_abi();
+        let claimer = claim
+            .signer_address(application_id)
+            .expect("Failed to verify signature");
+
+        let proven_balance = claim
+            .balance_proof
+            .as_ref()
+            .expect("Claims must carry a balance proof")
+            .verify(
+                &Network::Ethereum,
+                claimer,
+                &parameters.proof_of_sql_commitments,
+            )
+            .unwrap_or_else(|error| panic!("{error}"));
+
+        assert_eq!(
+            proven_balance.snapshot_block, parameters.snapshot_block,
+            "Balance proof is not for the airdrop's snapshot block"
+        );
+        assert!(
+            proven_balance.balance >= parameters.minimum_balance,
+            "Claimer is not eligible for the airdrop"
+        );
+
+        let message = Message::ApprovedClaim {
+            id: AirDropId::new(claim.campaign, claimer),
+            destination: claim.destination,
+        };
+
+        let creator_chain = self.runtime.application_creator_chain_id();
+
+        if self.runtime.chain_id() == creator_chain {
+            self.handle_message(message).await;
+        } else {
+            self.runtime
+                .prepare_message(message)
+                .with_authentication()
+                .send_to(creator_chain);
+        }
+    }
+
+    /// Handles a [`Message`] on the application's creator chain.
+    async fn handle_message(&mut self, message: Message) {
+        match message {
+            Message::ApprovedClaim { id, destination } => self.pay(id, destination).await,
+        }
+    }
+
+    /// Pays an approved airdrop to its `destination`, unless it has already been paid.
+    async fn pay(&mut self, id: AirDropId, destination: Account) {
+        assert!(
+            !self
+                .state
+                .handled_airdrops
+                .contains(&id)
+                .await
+                .expect("Failed to read handled claims from storage"),
+            "Airdrop has already been paid"
+        );
+
+        self.state
+            .handled_airdrops
+            .insert(&id)
+            .expect("Failed to write handled claim to storage");
+
+        let parameters = self.runtime.application_parameters();
+        let source_account = AccountOwner::Application(self.runtime.application_id().forget_abi());
+
+        let transfer = fungible::Operation::Transfer {
+            owner: source_account,
+            amount: parameters.claim_amount,
+            target_account: destination,
+        };
+
+        self.runtime
+            .call_application(true, parameters.token_id, &transfer);
+    }
+}
+
+/// Messages sent to the application's creator chain.
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(test, derive(Clone, Eq, PartialEq))]
+pub enum Message {
+    /// A claim with a verified signature and balance proof, to be paid if it hasn't been yet.
+    ApprovedClaim { id: AirDropId, destination: Account },
+}
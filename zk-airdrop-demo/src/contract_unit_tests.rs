@@ -0,0 +1,300 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{Arc, Mutex};
+
+use airdrop_demo::{
+    balance_proof::{BalanceProof, TableCommitments},
+    network::Network,
+    test_utils::{
+        balance_proofs::MockProver, create_dummy_application_id, create_dummy_token_id,
+        mock_gateway::BalanceRecord, sign_claim,
+    },
+    AirDropClaim, AirDropId, CampaignId,
+};
+use alloy_primitives::{Address, U256};
+use k256::ecdsa::SigningKey;
+use linera_sdk::{
+    abis::fungible::{self, Account, FungibleResponse},
+    base::{AccountOwner, Amount, ApplicationId, ChainId, CryptoHash, Owner},
+    bcs,
+    util::BlockingWait,
+    views::View,
+    Contract, ContractRuntime,
+};
+use rand::rngs::OsRng;
+use zk_airdrop_demo::{ApplicationAbi, Operation, Parameters, AIRDROP_CAMPAIGN};
+
+use super::{state::Application, ApplicationContract, Message};
+
+/// Tests if an approved claim leads to a call to transfer the tokens to the claimer.
+#[test]
+fn pays_approved_claim() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let airdrop_id = AirDropId::new(AIRDROP_CAMPAIGN, Address::random());
+    let destination = create_dummy_destination();
+
+    contract.runtime.set_call_application_handler(
+        move |is_authenticated, target_application, operation| {
+            assert!(is_authenticated);
+            assert_eq!(target_application, create_dummy_token_id());
+            assert_eq!(
+                operation,
+                bcs::to_bytes(&fungible::Operation::Transfer {
+                    owner: AccountOwner::Application(application_id.forget_abi()),
+                    amount: CLAIM_AMOUNT,
+                    target_account: destination,
+                })
+                .expect("Payment transfer should be serializable")
+            );
+
+            bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+        },
+    );
+
+    let () = contract
+        .execute_message(Message::ApprovedClaim {
+            id: airdrop_id,
+            destination,
+        })
+        .blocking_wait();
+
+    assert!(contract
+        .state
+        .handled_airdrops
+        .contains(&airdrop_id)
+        .blocking_wait()
+        .expect("Failed to read handled claims"));
+}
+
+/// Tests if a claim with a valid balance proof is paid by transferring the tokens to the
+/// claimer.
+#[test]
+fn pays_claim_with_valid_proof() {
+    let signing_key = SigningKey::random(&mut OsRng);
+    let claimer = Address::from_private_key(&signing_key);
+    let prover = MockProver::new(
+        Network::Ethereum,
+        [BalanceRecord {
+            address: claimer,
+            block: SNAPSHOT_BLOCK - 10,
+            balance: MINIMUM_BALANCE,
+        }],
+    );
+    let (mut contract, application_id) =
+        create_and_instantiate_contract_with(prover.serialized_commitments());
+    let balance_proof = prover.prove(claimer, SNAPSHOT_BLOCK);
+    let claim = create_signed_claim(
+        &signing_key,
+        application_id,
+        AIRDROP_CAMPAIGN,
+        Some(balance_proof),
+    );
+    let transfers = Arc::new(Mutex::new(Vec::new()));
+
+    contract.runtime.set_call_application_handler({
+        let transfers = transfers.clone();
+        move |is_authenticated, target_application, operation| {
+            assert!(is_authenticated);
+            assert_eq!(target_application, create_dummy_token_id());
+            transfers
+                .lock()
+                .expect("Failed to lock transfers")
+                .push(operation);
+
+            bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+        }
+    });
+
+    let () = contract
+        .execute_operation(Operation::Claim(claim))
+        .blocking_wait();
+
+    let expected_transfer = bcs::to_bytes(&fungible::Operation::Transfer {
+        owner: AccountOwner::Application(application_id.forget_abi()),
+        amount: CLAIM_AMOUNT,
+        target_account: create_dummy_destination(),
+    })
+    .expect("Payment transfer should be serializable");
+
+    assert_eq!(
+        *transfers.lock().expect("Failed to lock transfers"),
+        vec![expected_transfer]
+    );
+    assert!(contract
+        .state
+        .handled_airdrops
+        .contains(&AirDropId::new(AIRDROP_CAMPAIGN, claimer))
+        .blocking_wait()
+        .expect("Failed to read handled claims"));
+}
+
+/// Tests if the same airdrop pays the claimer once.
+#[test]
+#[should_panic(expected = "Airdrop has already been paid")]
+fn rejects_repeated_airdrop() {
+    let (mut contract, _) = create_and_instantiate_contract();
+    let airdrop_id = AirDropId::new(AIRDROP_CAMPAIGN, Address::random());
+
+    contract.runtime.set_call_application_handler(|_, _, _| {
+        bcs::to_bytes(&FungibleResponse::Ok).expect("Unit type should be serializable")
+    });
+
+    for _ in 0..2 {
+        let () = contract
+            .execute_message(Message::ApprovedClaim {
+                id: airdrop_id,
+                destination: create_dummy_destination(),
+            })
+            .blocking_wait();
+    }
+}
+
+/// Tests if a claim without a balance proof is rejected.
+#[test]
+#[should_panic(expected = "Claims must carry a balance proof")]
+fn rejects_claim_without_proof() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let claim = create_signed_claim(
+        &SigningKey::random(&mut OsRng),
+        application_id,
+        AIRDROP_CAMPAIGN,
+        None,
+    );
+
+    let () = contract
+        .execute_operation(Operation::Claim(claim))
+        .blocking_wait();
+}
+
+/// Tests if a claim with a balance proof that can't be deserialized is rejected.
+#[test]
+#[should_panic(expected = "Balance proof could not be deserialized")]
+fn rejects_claim_with_malformed_proof() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let balance_proof = BalanceProof {
+        snapshot_block: SNAPSHOT_BLOCK,
+        verifiable_result: vec![0xff; 8],
+    };
+    let claim = create_signed_claim(
+        &SigningKey::random(&mut OsRng),
+        application_id,
+        AIRDROP_CAMPAIGN,
+        Some(balance_proof),
+    );
+
+    let () = contract
+        .execute_operation(Operation::Claim(claim))
+        .blocking_wait();
+}
+
+/// Tests if a claim signed for a campaign other than the airdrop's is rejected.
+#[test]
+#[should_panic(expected = "Claims must be signed for the airdrop's campaign")]
+fn rejects_claim_for_other_campaign() {
+    let (mut contract, application_id) = create_and_instantiate_contract();
+    let claim = create_signed_claim(
+        &SigningKey::random(&mut OsRng),
+        application_id,
+        CampaignId(1),
+        None,
+    );
+
+    let () = contract
+        .execute_operation(Operation::Claim(claim))
+        .blocking_wait();
+}
+
+/// The amount paid for each claim in the tests.
+const CLAIM_AMOUNT: Amount = Amount::ONE;
+
+/// The snapshot block configured in the tests' [`Parameters`].
+const SNAPSHOT_BLOCK: u64 = 100;
+
+/// The minimum balance configured in the tests' [`Parameters`].
+const MINIMUM_BALANCE: U256 = U256::from_limbs([1, 0, 0, 0]);
+
+/// Creates an [`ApplicationContract`] instance and calls `instantiate` on it.
+///
+/// Returns the [`ApplicationContract`] instance along with a dummy [`ApplicationId`] that was
+/// assigned to it.
+fn create_and_instantiate_contract() -> (ApplicationContract, ApplicationId<ApplicationAbi>) {
+    create_and_instantiate_contract_with(
+        bcs::to_bytes(&TableCommitments::default())
+            .expect("Empty commitments should be serializable"),
+    )
+}
+
+/// Creates an [`ApplicationContract`] instance that verifies balance proofs against the BCS
+/// serialized `proof_of_sql_commitments`, and calls `instantiate` on it.
+fn create_and_instantiate_contract_with(
+    proof_of_sql_commitments: Vec<u8>,
+) -> (ApplicationContract, ApplicationId<ApplicationAbi>) {
+    let application_id = create_dummy_application_id("zk-airdrop", 1);
+    let creator_chain = ChainId(CryptoHash::test_hash("creator chain"));
+
+    let mut runtime = ContractRuntime::new()
+        .with_application_parameters(Parameters {
+            token_id: create_dummy_token_id(),
+            proof_of_sql_commitments,
+            snapshot_block: SNAPSHOT_BLOCK,
+            minimum_balance: MINIMUM_BALANCE,
+            claim_amount: CLAIM_AMOUNT,
+        })
+        .with_application_id(application_id)
+        .with_application_creator_chain_id(creator_chain)
+        .with_chain_id(creator_chain);
+
+    runtime.set_call_application_handler(|_, target_application, _| {
+        assert_eq!(target_application, create_dummy_token_id());
+
+        bcs::to_bytes(&FungibleResponse::Balance(Amount::ZERO))
+            .expect("Balance response should be serializable")
+    });
+
+    let mut contract = ApplicationContract {
+        state: Application::load(runtime.root_view_storage_context())
+            .blocking_wait()
+            .expect("Failed to read from mock key value store"),
+        runtime,
+    };
+
+    contract.instantiate(()).blocking_wait();
+
+    (contract, application_id)
+}
+
+/// Creates an [`AirDropClaim`] signed by the `signing_key` for a `campaign`.
+fn create_signed_claim(
+    signing_key: &SigningKey,
+    application_id: ApplicationId<ApplicationAbi>,
+    campaign: CampaignId,
+    balance_proof: Option<BalanceProof>,
+) -> AirDropClaim {
+    let destination = create_dummy_destination();
+    let signature = sign_claim(
+        signing_key,
+        application_id.forget_abi().with_abi(),
+        campaign,
+        destination,
+        None,
+    );
+
+    AirDropClaim {
+        campaign,
+        signature: signature.into(),
+        destination,
+        api_token: String::new(),
+        relayer_fee: None,
+        balance_proof,
+        allowlist_proof: None,
+    }
+}
+
+/// Creates a dummy [`Account`] to use as a test destination for the airdropped tokens.
+fn create_dummy_destination() -> Account {
+    Account {
+        chain_id: ChainId(CryptoHash::test_hash("destination chain")),
+        owner: AccountOwner::User(Owner(CryptoHash::test_hash("destination owner"))),
+    }
+}
@@ -1,13 +1,44 @@
-use linera_sdk::base::{ContractAbi, ServiceAbi};
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use airdrop_demo::{AirDropClaim, CampaignId};
+use alloy_primitives::U256;
+use linera_sdk::{
+    abis::fungible::FungibleTokenAbi,
+    base::{Amount, ApplicationId, ContractAbi, ServiceAbi},
+};
+use serde::{Deserialize, Serialize};
 
 pub struct ApplicationAbi;
 
 impl ContractAbi for ApplicationAbi {
-    type Operation = ();
+    type Operation = Operation;
     type Response = ();
 }
 
 impl ServiceAbi for ApplicationAbi {
-    type Query = ();
-    type QueryResponse = ();
+    type Query = async_graphql::Request;
+    type QueryResponse = async_graphql::Response;
+}
+
+/// The shared parameters that are specified when the application is instantiated.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Parameters {
+    pub token_id: ApplicationId<FungibleTokenAbi>,
+    /// The BCS serialized Proof-of-SQL commitments to the Space-and-Time tables that the
+    /// claimers' balance proofs are verified against.
+    pub proof_of_sql_commitments: Vec<u8>,
+    pub snapshot_block: u64,
+    pub minimum_balance: U256,
+    pub claim_amount: Amount,
 }
+
+/// The operations that can be executed by the application.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Operation {
+    /// Claims an airdrop, proving the claimer's eligibility with a balance proof.
+    Claim(AirDropClaim),
+}
+
+/// The campaign that all claims must be signed for, since the application runs a single airdrop.
+pub const AIRDROP_CAMPAIGN: CampaignId = CampaignId(0);
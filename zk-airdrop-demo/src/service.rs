@@ -1,17 +1,21 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
 mod state;
 
+use std::sync::Arc;
+
+use airdrop_demo::{graphql::AirDropIdInput, network::Network, AirDropClaim};
+use async_graphql::{EmptySubscription, Schema};
+use linera_sdk::{base::WithServiceAbi, bcs, views::View, Service, ServiceRuntime};
+use zk_airdrop_demo::{Operation, Parameters};
+
 use self::state::Application;
-use linera_sdk::{
-    base::WithServiceAbi,
-    views::{View, ViewStorageContext},
-    Service, ServiceRuntime,
-};
 
 pub struct ApplicationService {
-    state: Application,
-    runtime: ServiceRuntime<Self>,
+    schema: Schema<Query, Mutation, EmptySubscription>,
 }
 
 linera_sdk::service!(ApplicationService);
@@ -21,16 +25,49 @@ impl WithServiceAbi for ApplicationService {
 }
 
 impl Service for ApplicationService {
-    type Parameters = ();
+    type Parameters = Parameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
-        let state = Application::load(ViewStorageContext::from(runtime.key_value_store()))
+        let state = Application::load(runtime.root_view_storage_context())
             .await
             .expect("Failed to load state");
-        ApplicationService { state, runtime }
+
+        let query = Query {
+            state: Arc::new(state),
+        };
+
+        ApplicationService {
+            schema: Schema::build(query, Mutation, EmptySubscription).finish(),
+        }
+    }
+
+    async fn handle_query(&self, query: Self::Query) -> Self::QueryResponse {
+        self.schema.execute(query).await
     }
+}
+
+/// Root type that defines all the GraphQL queries available from the service.
+pub struct Query {
+    state: Arc<Application>,
+}
+
+#[async_graphql::Object]
+impl Query {
+    /// Checks if an airdrop has already been paid.
+    async fn is_paid(&self, id: AirDropIdInput) -> async_graphql::Result<bool> {
+        let id = id.into_airdrop_id(&Network::Ethereum)?;
+
+        Ok(self.state.handled_airdrops.contains(&id).await?)
+    }
+}
+
+/// Root type that defines all the GraphQL mutations available from the service.
+pub struct Mutation;
 
-    async fn handle_query(&self, _query: Self::Query) -> Self::QueryResponse {
-        panic!("Queries not supported by application");
+#[async_graphql::Object]
+impl Mutation {
+    /// Claims the airdrop, with a balance proof for the claimer's eligibility.
+    async fn claim(&self, claim: AirDropClaim) -> Vec<u8> {
+        bcs::to_bytes(&Operation::Claim(claim)).expect("`Operation` should be serializable")
     }
 }
@@ -0,0 +1,105 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::collections::BTreeMap;
+
+use airdrop_demo::{
+    balance_proof::TableCommitments, test_utils::TestClaimer, AirDropId, ETHEREUM_MAINNET_CHAIN_ID,
+};
+use alloy_primitives::{Address, U256};
+use linera_sdk::{
+    abis::fungible,
+    base::{AccountOwner, Amount, ApplicationId},
+    bcs,
+    test::{ActiveChain, TestValidator},
+};
+use zk_airdrop_demo::{ApplicationAbi, Operation, Parameters, AIRDROP_CAMPAIGN};
+
+/// Tests if the service reports airdrops that haven't been claimed as unpaid.
+#[tokio::test]
+async fn reports_unpaid_airdrop() {
+    let (_validator, airdrop_chain, application_id) = setup().await;
+    let airdrop_id = AirDropId::new(AIRDROP_CAMPAIGN, Address::random());
+
+    let address = airdrop_id
+        .external_address()
+        .to_checksum(Some(ETHEREUM_MAINNET_CHAIN_ID));
+    let query = format!(
+        "query {{ isPaid(id: {{ campaign: {AIRDROP_CAMPAIGN}, address: \"{address}\" }}) }}"
+    );
+
+    let response = airdrop_chain.graphql_query(application_id, query).await;
+
+    assert_eq!(response["isPaid"].as_bool(), Some(false));
+}
+
+/// Tests if a claim without a balance proof is rejected by the chain that submits it.
+#[tokio::test]
+#[should_panic]
+async fn rejects_claim_without_proof() {
+    let (validator, _airdrop_chain, application_id) = setup().await;
+
+    let claimer_chain = validator.new_chain().await;
+    let destination = fungible::Account {
+        chain_id: claimer_chain.id(),
+        owner: AccountOwner::from(claimer_chain.public_key()),
+    };
+
+    let claim = Operation::Claim(
+        TestClaimer::new(0)
+            .claim(
+                application_id.forget_abi().with_abi(),
+                AIRDROP_CAMPAIGN,
+                destination,
+            )
+            .build(),
+    );
+
+    claimer_chain.register_application(application_id).await;
+    claimer_chain
+        .add_block(|block| {
+            block.with_operation(application_id, claim);
+        })
+        .await;
+}
+
+/// Configures the test environment, deploying the zk-airdrop application.
+async fn setup() -> (TestValidator, ActiveChain, ApplicationId<ApplicationAbi>) {
+    let (validator, bytecode_id) =
+        TestValidator::with_current_bytecode::<ApplicationAbi, Parameters, ()>().await;
+
+    let mut airdrop_chain = validator.new_chain().await;
+
+    let fungible_bytecode_id = airdrop_chain
+        .publish_bytecodes_in("../vendor/linera-protocol/examples/fungible")
+        .await;
+    let token_id = airdrop_chain
+        .create_application(
+            fungible_bytecode_id,
+            fungible::Parameters {
+                ticker_symbol: "TOK".to_owned(),
+            },
+            fungible::InitialState {
+                accounts: BTreeMap::new(),
+            },
+            vec![],
+        )
+        .await;
+
+    let application_id = airdrop_chain
+        .create_application(
+            bytecode_id,
+            Parameters {
+                token_id,
+                proof_of_sql_commitments: bcs::to_bytes(&TableCommitments::default())
+                    .expect("Empty commitments should be serializable"),
+                snapshot_block: 250,
+                minimum_balance: U256::from(25),
+                claim_amount: Amount::ONE,
+            },
+            (),
+            vec![token_id.forget_abi()],
+        )
+        .await;
+
+    (validator, airdrop_chain, application_id)
+}